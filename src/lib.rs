@@ -8,6 +8,17 @@ pub enum Orient {
     Zero,
 }
 
+impl Orient {
+    pub fn reverse(self) -> Self {
+        // the orientation seen traversing the other way
+        match self {
+            Orient::Negative => Orient::Positive,
+            Orient::Positive => Orient::Negative,
+            Orient::Zero => Orient::Zero,
+        }
+    }
+}
+
 pub fn add(left: usize, right: usize) -> usize {
     left + right
 }
@@ -21,4 +32,11 @@ mod tests {
         let result = add(2, 2);
         assert_eq!(result, 4);
     }
+
+    #[test]
+    fn orient_reverse() {
+        assert_eq!(Orient::Positive.reverse(), Orient::Negative);
+        assert_eq!(Orient::Negative.reverse(), Orient::Positive);
+        assert_eq!(Orient::Zero.reverse(), Orient::Zero);
+    }
 }
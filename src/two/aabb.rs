@@ -0,0 +1,90 @@
+use super::Point;
+
+// an axis-aligned bounding box
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Aabb {
+    pub min: Point,
+    pub max: Point,
+}
+
+impl Aabb {
+    pub fn from_corners(a: Point, b: Point) -> Self {
+        // any two opposite corners, in any order
+        Aabb {
+            min: a.min(b),
+            max: a.max(b),
+        }
+    }
+
+    pub fn from_points<T: IntoIterator<Item = Point>>(points: T) -> Self {
+        // smallest box containing all points, panics if there are none
+        let mut points = points.into_iter();
+        let first = points.next().expect("bounding box of no points");
+
+        points.fold(Aabb::from_corners(first, first), |acc, p| {
+            acc.union(&Aabb::from_corners(p, p))
+        })
+    }
+
+    pub fn contains(&self, p: Point) -> bool {
+        // points on the edge of the box are contained
+        (self.min.x..=self.max.x).contains(&p.x) && (self.min.y..=self.max.y).contains(&p.y)
+    }
+
+    pub fn intersects(&self, other: &Aabb) -> bool {
+        // boxes touching along an edge or corner intersect
+        self.min.x <= other.max.x
+            && other.min.x <= self.max.x
+            && self.min.y <= other.max.y
+            && other.min.y <= self.max.y
+    }
+
+    pub fn union(&self, other: &Aabb) -> Aabb {
+        Aabb {
+            min: self.min.min(other.min),
+            max: self.max.max(other.max),
+        }
+    }
+
+    pub fn width(&self) -> f64 {
+        self.max.x - self.min.x
+    }
+
+    pub fn height(&self) -> f64 {
+        self.max.y - self.min.y
+    }
+
+    pub fn center(&self) -> Point {
+        self.min.mid(self.max)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn aabb_contains() {
+        let b = Aabb::from_corners((2.0, 0.0).into(), (0.0, 1.0).into());
+        assert_eq!(b.min, Point::new(0.0, 0.0));
+        assert_eq!(b.max, Point::new(2.0, 1.0));
+        assert_eq!((b.width(), b.height()), (2.0, 1.0));
+        assert_eq!(b.center(), Point::new(1.0, 0.5));
+
+        assert!(b.contains((1.0, 0.5).into()));
+        assert!(b.contains((2.0, 1.0).into()));
+        assert!(!b.contains((2.5, 0.5).into()));
+        assert!(!b.contains((1.0, -0.1).into()));
+    }
+
+    #[test]
+    fn aabb_union() {
+        let a = Aabb::from_points(vec![(0.0, 0.0).into(), (1.0, 1.0).into()]);
+        let b = Aabb::from_points(vec![(3.0, -1.0).into(), (2.0, 0.5).into()]);
+        assert!(!a.intersects(&b));
+
+        let u = a.union(&b);
+        assert_eq!(u, Aabb::from_corners((0.0, -1.0).into(), (3.0, 1.0).into()));
+        assert!(u.intersects(&a) && u.intersects(&b));
+    }
+}
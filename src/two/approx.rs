@@ -0,0 +1,91 @@
+// tolerant equality, for comparing shapes built along different routes
+// lengths are compared to within tol, and angles to within tol radians
+
+use super::{Arc, Circle, Point, Segment};
+
+pub trait ApproxEq {
+    fn approx_eq(&self, other: &Self, tol: f64) -> bool;
+}
+
+fn ang_eq(a: f64, b: f64, tol: f64) -> bool {
+    // equal as directions, so angles either side of zero compare as close
+    let d = (a - b).rem_euclid(std::f64::consts::TAU);
+    d.min(std::f64::consts::TAU - d) <= tol
+}
+
+impl ApproxEq for Point {
+    fn approx_eq(&self, other: &Point, tol: f64) -> bool {
+        self.eps_eq(*other, tol)
+    }
+}
+
+impl Segment {
+    pub fn approx_eq_oriented(&self, other: &Segment, tol: f64, oriented: bool) -> bool {
+        // as approx_eq, but if oriented is false a segment also equals its reverse
+        let same = self.p().approx_eq(&other.p(), tol) && self.q().approx_eq(&other.q(), tol);
+        let reversed = self.p().approx_eq(&other.q(), tol) && self.q().approx_eq(&other.p(), tol);
+        same || (!oriented && reversed)
+    }
+}
+
+impl ApproxEq for Segment {
+    fn approx_eq(&self, other: &Segment, tol: f64) -> bool {
+        self.approx_eq_oriented(other, tol, true)
+    }
+}
+
+impl ApproxEq for Circle {
+    fn approx_eq(&self, other: &Circle, tol: f64) -> bool {
+        self.center.approx_eq(&other.center, tol) && (self.radius - other.radius).abs() <= tol
+    }
+}
+
+impl ApproxEq for Arc {
+    fn approx_eq(&self, other: &Arc, tol: f64) -> bool {
+        self.ccw() == other.ccw()
+            && self.to_circle().approx_eq(&other.to_circle(), tol)
+            && ang_eq(self.p_ang(), other.p_ang(), tol)
+            && ang_eq(self.q_ang(), other.q_ang(), tol)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn segment_orientation() {
+        let s = Segment::new((1.0, 2.0).into(), (3.0, -1.0).into());
+        let r = s.reverse();
+        assert!(s.approx_eq(&s, 0.0));
+        assert!(!s.approx_eq(&r, 1e-9));
+        assert!(s.approx_eq_oriented(&r, 1e-9, false));
+        assert!(!s.approx_eq_oriented(&r, 1e-9, true));
+
+        let nudged = Segment::new((1.0, 2.0 + 1e-10).into(), (3.0, -1.0).into());
+        assert!(s.approx_eq(&nudged, 1e-9));
+        assert!(!s.approx_eq(&nudged, 1e-11));
+    }
+
+    #[test]
+    fn circle_arc() {
+        let c = Circle::new((1.0, 1.0).into(), 2.0);
+        assert!(c.approx_eq(&Circle::new((1.0, 1.0 + 1e-12).into(), 2.0 + 1e-12), 1e-9));
+        assert!(!c.approx_eq(&Circle::new((1.0, 1.0).into(), 2.1), 1e-9));
+
+        // angles just either side of zero are close
+        let a = Arc::from_center_ang((1.0, 1.0).into(), 2.0, 1e-12, 1.0, true);
+        let b = Arc::from_center_ang(
+            (1.0, 1.0).into(),
+            2.0,
+            std::f64::consts::TAU - 1e-12,
+            1.0,
+            true,
+        );
+        assert!(a.approx_eq(&b, 1e-9));
+        assert!(!a.approx_eq(&a.reverse(), 1e-9));
+
+        let c = Arc::from_center_ang((1.0, 1.0).into(), 2.0, 0.0, 1.1, true);
+        assert!(!a.approx_eq(&c, 1e-9));
+    }
+}
@@ -0,0 +1,270 @@
+use super::bezier::gauss;
+use super::boundary::Edge;
+use super::{Arc, CubicBezier, Point, Segment, Vector};
+
+// exact contributions of a single edge to the mass properties of a closed boundary
+// each term is a line integral along the edge (green's theorem), so summing the terms
+// over every edge of a closed boundary gives the property of the enclosed region
+// counterclockwise boundaries give positive area
+pub trait AreaContribution {
+    // 1/2 ∮ x dy - y dx
+    fn area_term(&self) -> f64;
+
+    // first moments (∬ x dA, ∬ y dA) as (∮ x^2/2 dy, -∮ y^2/2 dx)
+    fn centroid_term(&self) -> (f64, f64);
+
+    // second moments about the origin (∬ y^2 dA, ∬ x^2 dA, ∬ xy dA)
+    // as (-∮ y^3/3 dx, ∮ x^3/3 dy, ∮ x^2 y/2 dy)
+    fn moment_terms(&self) -> (f64, f64, f64);
+}
+
+impl AreaContribution for Segment {
+    fn area_term(&self) -> f64 {
+        self.p().shoelace(self.q()) / 2.0
+    }
+
+    fn centroid_term(&self) -> (f64, f64) {
+        let (p, q) = self.into_points();
+        let mx = (q.y - p.y) * (p.x.powi(2) + p.x * q.x + q.x.powi(2)) / 6.0;
+        let my = -(q.x - p.x) * (p.y.powi(2) + p.y * q.y + q.y.powi(2)) / 6.0;
+        (mx, my)
+    }
+
+    fn moment_terms(&self) -> (f64, f64, f64) {
+        // integrate the cubic polynomials along x = p + t(q - p), t in [0, 1]
+        let (p, q) = self.into_points();
+        let cube_sum = |a: f64, b: f64| a.powi(3) + a.powi(2) * b + a * b.powi(2) + b.powi(3);
+
+        let ixx = -(q.x - p.x) * cube_sum(p.y, q.y) / 12.0;
+        let iyy = (q.y - p.y) * cube_sum(p.x, q.x) / 12.0;
+
+        // ∫ x^2 y dt, from the exact integrals of t^k over [0, 1]
+        let (dx, dy) = (q.x - p.x, q.y - p.y);
+        let x2y = p.x.powi(2) * p.y
+            + (p.x.powi(2) * dy + 2.0 * p.x * dx * p.y) / 2.0
+            + (dx.powi(2) * p.y + 2.0 * p.x * dx * dy) / 3.0
+            + dx.powi(2) * dy / 4.0;
+        let ixy = dy * x2y / 2.0;
+
+        (ixx, iyy, ixy)
+    }
+}
+
+// antiderivatives of the trig powers met when integrating along a circle
+fn int_cos(n: u8, t: f64) -> f64 {
+    match n {
+        0 => t,
+        1 => t.sin(),
+        2 => t / 2.0 + (2.0 * t).sin() / 4.0,
+        3 => t.sin() - t.sin().powi(3) / 3.0,
+        4 => 3.0 * t / 8.0 + (2.0 * t).sin() / 4.0 + (4.0 * t).sin() / 32.0,
+        _ => unreachable!(),
+    }
+}
+
+fn int_sin(n: u8, t: f64) -> f64 {
+    match n {
+        0 => t,
+        1 => -t.cos(),
+        2 => t / 2.0 - (2.0 * t).sin() / 4.0,
+        3 => -t.cos() + t.cos().powi(3) / 3.0,
+        4 => 3.0 * t / 8.0 - (2.0 * t).sin() / 4.0 + (4.0 * t).sin() / 32.0,
+        _ => unreachable!(),
+    }
+}
+
+fn int_sin_cos(n: u8, t: f64) -> f64 {
+    // ∫ sin t cos^n t dt
+    -t.cos().powi(n as i32 + 1) / (n as f64 + 1.0)
+}
+
+impl AreaContribution for Arc {
+    // parametrized as center + r(cos t, sin t), with t running in the direction of travel
+
+    fn area_term(&self) -> f64 {
+        let (t0, t1) = self.pq_ang_unbounded();
+        let (c, r) = (self.center(), self.radius());
+        let int = |f: fn(u8, f64) -> f64, n: u8| f(n, t1) - f(n, t0);
+
+        (c.x * r * int(int_cos, 1) + c.y * r * int(int_sin, 1) + r.powi(2) * int(int_cos, 0)) / 2.0
+    }
+
+    fn centroid_term(&self) -> (f64, f64) {
+        let (t0, t1) = self.pq_ang_unbounded();
+        let (c, r) = (self.center(), self.radius());
+        let int = |f: fn(u8, f64) -> f64, n: u8| f(n, t1) - f(n, t0);
+
+        // (cx + r cos)^2 r cos / 2 and (cy + r sin)^2 r sin / 2
+        let mx = r / 2.0
+            * (c.x.powi(2) * int(int_cos, 1)
+                + 2.0 * c.x * r * int(int_cos, 2)
+                + r.powi(2) * int(int_cos, 3));
+        let my = r / 2.0
+            * (c.y.powi(2) * int(int_sin, 1)
+                + 2.0 * c.y * r * int(int_sin, 2)
+                + r.powi(2) * int(int_sin, 3));
+
+        (mx, my)
+    }
+
+    fn moment_terms(&self) -> (f64, f64, f64) {
+        let (t0, t1) = self.pq_ang_unbounded();
+        let (c, r) = (self.center(), self.radius());
+        let int = |f: fn(u8, f64) -> f64, n: u8| f(n, t1) - f(n, t0);
+
+        // (cy + r sin)^3 r sin / 3
+        let ixx = r / 3.0
+            * (c.y.powi(3) * int(int_sin, 1)
+                + 3.0 * c.y.powi(2) * r * int(int_sin, 2)
+                + 3.0 * c.y * r.powi(2) * int(int_sin, 3)
+                + r.powi(3) * int(int_sin, 4));
+
+        // (cx + r cos)^3 r cos / 3
+        let iyy = r / 3.0
+            * (c.x.powi(3) * int(int_cos, 1)
+                + 3.0 * c.x.powi(2) * r * int(int_cos, 2)
+                + 3.0 * c.x * r.powi(2) * int(int_cos, 3)
+                + r.powi(3) * int(int_cos, 4));
+
+        // (cx + r cos)^2 (cy + r sin) r cos / 2
+        let ixy = r / 2.0
+            * (c.x.powi(2) * c.y * int(int_cos, 1)
+                + c.x.powi(2) * r * int(int_sin_cos, 1)
+                + 2.0 * c.x * c.y * r * int(int_cos, 2)
+                + 2.0 * c.x * r.powi(2) * int(int_sin_cos, 2)
+                + c.y * r.powi(2) * int(int_cos, 3)
+                + r.powi(3) * int(int_sin_cos, 3));
+
+        (ixx, iyy, ixy)
+    }
+}
+
+impl AreaContribution for CubicBezier {
+    // the integrands are polynomials in t of degree at most 11, so gaussian quadrature with
+    // six nodes is exact
+
+    fn area_term(&self) -> f64 {
+        gauss(0.0, 1.0, |t| {
+            (self.point_at(t) - Point::ZERO).cross(self.tangent_at(t))
+        }) / 2.0
+    }
+
+    fn centroid_term(&self) -> (f64, f64) {
+        let along = |f: fn(Point, Vector) -> f64| {
+            gauss(0.0, 1.0, |t| f(self.point_at(t), self.tangent_at(t)))
+        };
+        let mx = along(|p, d| p.x.powi(2) / 2.0 * d.y);
+        let my = along(|p, d| -p.y.powi(2) / 2.0 * d.x);
+        (mx, my)
+    }
+
+    fn moment_terms(&self) -> (f64, f64, f64) {
+        let along = |f: fn(Point, Vector) -> f64| {
+            gauss(0.0, 1.0, |t| f(self.point_at(t), self.tangent_at(t)))
+        };
+        let ixx = along(|p, d| -p.y.powi(3) / 3.0 * d.x);
+        let iyy = along(|p, d| p.x.powi(3) / 3.0 * d.y);
+        let ixy = along(|p, d| p.x.powi(2) * p.y / 2.0 * d.y);
+        (ixx, iyy, ixy)
+    }
+}
+
+impl AreaContribution for Edge {
+    fn area_term(&self) -> f64 {
+        match self {
+            Edge::Arc(a) => a.area_term(),
+            Edge::Segment(s) => s.area_term(),
+            Edge::Bezier(b) => b.area_term(),
+        }
+    }
+
+    fn centroid_term(&self) -> (f64, f64) {
+        match self {
+            Edge::Arc(a) => a.centroid_term(),
+            Edge::Segment(s) => s.centroid_term(),
+            Edge::Bezier(b) => b.centroid_term(),
+        }
+    }
+
+    fn moment_terms(&self) -> (f64, f64, f64) {
+        match self {
+            Edge::Arc(a) => a.moment_terms(),
+            Edge::Segment(s) => s.moment_terms(),
+            Edge::Bezier(b) => b.moment_terms(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::two::Boundary;
+    use std::f64::consts::PI;
+
+    fn half_disk(r: f64) -> Boundary {
+        let edges: Vec<Edge> = vec![
+            Arc::from_center_ang((0.0, 0.0).into(), r, 0.0, PI, true).into(),
+            Segment::new((-r, 0.0).into(), (r, 0.0).into()).into(),
+        ];
+        Boundary::new(edges)
+    }
+
+    #[test]
+    fn half_disk_terms() {
+        let r = 2.0;
+        let bound = half_disk(r);
+
+        let area: f64 = bound.edges().map(|e| e.area_term()).sum();
+        assert!((area - PI * r.powi(2) / 2.0).abs() < 1e-12);
+
+        let my: f64 = bound.edges().map(|e| e.centroid_term().1).sum();
+        assert!((my / area - 4.0 * r / (3.0 * PI)).abs() < 1e-12);
+
+        let (ixx, iyy, ixy) = bound.second_moments();
+        assert!((ixx - PI * r.powi(4) / 8.0).abs() < 1e-12);
+        assert!((iyy - PI * r.powi(4) / 8.0).abs() < 1e-12);
+        assert!(ixy.abs() < 1e-12);
+    }
+
+    #[test]
+    fn offset_moments() {
+        // offset rectangle [1, 3] x [2, 3]
+        let rect = Boundary::from_points(&[
+            (1.0, 2.0).into(),
+            (3.0, 2.0).into(),
+            (3.0, 3.0).into(),
+            (1.0, 3.0).into(),
+        ]);
+
+        let (ixx, iyy, ixy) = rect.second_moments();
+        assert!((ixx - 2.0 * (27.0 - 8.0) / 3.0).abs() < 1e-12);
+        assert!((iyy - (27.0 - 1.0) / 3.0).abs() < 1e-12);
+        assert!((ixy - 4.0 * 2.5).abs() < 1e-12);
+
+        // a quarter disk offset from the origin
+        let (cx, cy, r) = (1.0, 2.0, 1.5);
+        let edges: Vec<Edge> = vec![
+            Segment::new((cx, cy).into(), (cx + r, cy).into()).into(),
+            Arc::from_center_ang((cx, cy).into(), r, 0.0, PI / 2.0, true).into(),
+            Segment::new((cx, cy + r).into(), (cx, cy).into()).into(),
+        ];
+        let quarter = Boundary::new(edges);
+
+        // about the center: ixx = iyy = pi r^4 / 16, ixy = r^4 / 8, then shift by parallel axes
+        let area = PI * r.powi(2) / 4.0;
+        let (gx, gy) = (cx + 4.0 * r / (3.0 * PI), cy + 4.0 * r / (3.0 * PI));
+        let (ixx, iyy, ixy) = quarter.second_moments();
+        assert!(
+            (ixx - (PI * r.powi(4) / 16.0 + area * (cy.powi(2) + 2.0 * cy * (gy - cy)))).abs()
+                < 1e-12
+        );
+        assert!(
+            (iyy - (PI * r.powi(4) / 16.0 + area * (cx.powi(2) + 2.0 * cx * (gx - cx)))).abs()
+                < 1e-12
+        );
+        assert!(
+            (ixy - (r.powi(4) / 8.0 + area * (cx * cy + cx * (gy - cy) + cy * (gx - cx)))).abs()
+                < 1e-12
+        );
+    }
+}
@@ -0,0 +1,432 @@
+// quadratic and cubic bezier curves, as found in svg paths and font outlines
+// both run from their first control point at t = 0 to their last at t = 1
+
+use super::aabb::Aabb;
+use super::dist::Dist;
+use super::{Line, Point, Segment, Vector};
+
+// flattening gives up subdividing this deep, whatever the tolerance
+const MAX_DEPTH: usize = 24;
+
+// six point gauss-legendre rule on [-1, 1], exact for polynomials up to degree 11, which
+// covers every integrand of the mass properties of a cubic
+const GAUSS_NODES: [f64; 6] = [
+    -0.932_469_514_203_152,
+    -0.661_209_386_466_264_5,
+    -0.238_619_186_083_196_9,
+    0.238_619_186_083_196_9,
+    0.661_209_386_466_264_5,
+    0.932_469_514_203_152,
+];
+const GAUSS_WEIGHTS: [f64; 6] = [
+    0.171_324_492_379_170_4,
+    0.360_761_573_048_138_6,
+    0.467_913_934_572_691,
+    0.467_913_934_572_691,
+    0.360_761_573_048_138_6,
+    0.171_324_492_379_170_4,
+];
+
+pub(crate) fn gauss(a: f64, b: f64, f: impl Fn(f64) -> f64) -> f64 {
+    // integral of f over [a, b]
+    let (mid, half) = ((a + b) / 2.0, (b - a) / 2.0);
+    GAUSS_NODES
+        .iter()
+        .zip(GAUSS_WEIGHTS.iter())
+        .map(|(x, w)| w * f(mid + half * x))
+        .sum::<f64>()
+        * half
+}
+
+// arc length integrals are split into this many pieces, as the speed is not a polynomial
+const LENGTH_PIECES: usize = 16;
+
+fn hull_within(controls: &[Point], tol: f64) -> bool {
+    // whether every control point lies within tol of the chord
+    // the curve lies in the hull of its control points, so it is then within tol too
+    let chord = Segment::new(controls[0], controls[controls.len() - 1]);
+    controls.iter().all(|&c| chord.dist(c) <= tol)
+}
+
+fn extend_at_roots(bbox: &mut Aabb, point_at: impl Fn(f64) -> Point, roots: &[f64]) {
+    for &t in roots.iter().filter(|&&t| 0.0 < t && t < 1.0) {
+        let p = point_at(t);
+        *bbox = bbox.union(&Aabb::from_corners(p, p));
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct QuadBezier(pub Point, pub Point, pub Point);
+
+impl QuadBezier {
+    pub fn point_at(&self, t: f64) -> Point {
+        let QuadBezier(p0, p1, p2) = *self;
+        p0.lerp(p1, t).lerp(p1.lerp(p2, t), t)
+    }
+
+    pub fn tangent_at(&self, t: f64) -> Vector {
+        // the derivative with respect to t, not normalized
+        let QuadBezier(p0, p1, p2) = *self;
+        ((p1 - p0) * (1.0 - t) + (p2 - p1) * t) * 2.0
+    }
+
+    pub fn split(&self, t: f64) -> (Self, Self) {
+        // de casteljau: the halves before and after t
+        let QuadBezier(p0, p1, p2) = *self;
+        let (a, b) = (p0.lerp(p1, t), p1.lerp(p2, t));
+        let mid = a.lerp(b, t);
+        (QuadBezier(p0, a, mid), QuadBezier(mid, b, p2))
+    }
+
+    pub fn bounding_box(&self) -> Aabb {
+        // the ends, and wherever the derivative is zero in x or y
+        let QuadBezier(p0, p1, p2) = *self;
+        let root = |a: f64, b: f64, c: f64| (a - b) / (a - 2.0 * b + c);
+
+        let mut bbox = Aabb::from_corners(p0, p2);
+        let roots = [root(p0.x, p1.x, p2.x), root(p0.y, p1.y, p2.y)];
+        extend_at_roots(&mut bbox, |t| self.point_at(t), &roots);
+        bbox
+    }
+
+    pub fn flatten(&self, tol: f64) -> Vec<Segment> {
+        // segments within tol of the curve, by halving until each piece is flat enough
+        let mut res = Vec::new();
+        self.flatten_into(tol, 0, &mut res);
+        res
+    }
+
+    fn flatten_into(&self, tol: f64, depth: usize, res: &mut Vec<Segment>) {
+        let QuadBezier(p0, p1, p2) = *self;
+        if depth >= MAX_DEPTH || hull_within(&[p0, p1, p2], tol) {
+            res.push(Segment::new(p0, p2));
+            return;
+        }
+
+        let (a, b) = self.split(0.5);
+        a.flatten_into(tol, depth + 1, res);
+        b.flatten_into(tol, depth + 1, res);
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct CubicBezier(pub Point, pub Point, pub Point, pub Point);
+
+impl CubicBezier {
+    pub fn point_at(&self, t: f64) -> Point {
+        let CubicBezier(p0, p1, p2, p3) = *self;
+        QuadBezier(p0.lerp(p1, t), p1.lerp(p2, t), p2.lerp(p3, t)).point_at(t)
+    }
+
+    pub fn tangent_at(&self, t: f64) -> Vector {
+        // the derivative with respect to t, not normalized
+        let CubicBezier(p0, p1, p2, p3) = *self;
+        // a quadratic in the control differences
+        let (d0, d1, d2) = (p1 - p0, p2 - p1, p3 - p2);
+        let s = 1.0 - t;
+        (d0 * (s * s) + d1 * (2.0 * s * t) + d2 * (t * t)) * 3.0
+    }
+
+    pub fn split(&self, t: f64) -> (Self, Self) {
+        // de casteljau: the halves before and after t
+        let CubicBezier(p0, p1, p2, p3) = *self;
+        let (a, b, c) = (p0.lerp(p1, t), p1.lerp(p2, t), p2.lerp(p3, t));
+        let (d, e) = (a.lerp(b, t), b.lerp(c, t));
+        let mid = d.lerp(e, t);
+        (CubicBezier(p0, a, d, mid), CubicBezier(mid, e, c, p3))
+    }
+
+    pub fn bounding_box(&self) -> Aabb {
+        // the ends, and wherever the derivative is zero in x or y
+        let CubicBezier(p0, p1, p2, p3) = *self;
+
+        // the derivative is 3 (a t^2 + b t + c) in each coordinate
+        let roots = |p0: f64, p1: f64, p2: f64, p3: f64| -> Vec<f64> {
+            let a = -p0 + 3.0 * p1 - 3.0 * p2 + p3;
+            let b = 2.0 * (p0 - 2.0 * p1 + p2);
+            let c = p1 - p0;
+            if a.abs() < 1e-12 * (b.abs() + c.abs()) || a == 0.0 {
+                return vec![-c / b];
+            }
+            let disc = b * b - 4.0 * a * c;
+            if disc < 0.0 {
+                return Vec::new();
+            }
+            let root = disc.sqrt();
+            vec![(-b + root) / (2.0 * a), (-b - root) / (2.0 * a)]
+        };
+
+        let mut bbox = Aabb::from_corners(p0, p3);
+        let mut ts = roots(p0.x, p1.x, p2.x, p3.x);
+        ts.extend(roots(p0.y, p1.y, p2.y, p3.y));
+        extend_at_roots(&mut bbox, |t| self.point_at(t), &ts);
+        bbox
+    }
+
+    pub fn flatten(&self, tol: f64) -> Vec<Segment> {
+        // segments within tol of the curve, by halving until each piece is flat enough
+        let mut res = Vec::new();
+        self.flatten_into(tol, 0, &mut res);
+        res
+    }
+
+    fn flatten_into(&self, tol: f64, depth: usize, res: &mut Vec<Segment>) {
+        let CubicBezier(p0, p1, p2, p3) = *self;
+        if depth >= MAX_DEPTH || hull_within(&[p0, p1, p2, p3], tol) {
+            res.push(Segment::new(p0, p3));
+            return;
+        }
+
+        let (a, b) = self.split(0.5);
+        a.flatten_into(tol, depth + 1, res);
+        b.flatten_into(tol, depth + 1, res);
+    }
+}
+
+impl CubicBezier {
+    pub fn controls(&self) -> [Point; 4] {
+        [self.0, self.1, self.2, self.3]
+    }
+
+    pub(crate) fn map_controls(&self, f: impl Fn(Point) -> Point) -> CubicBezier {
+        // only valid for affine f, which commute with the curve's construction
+        CubicBezier(f(self.0), f(self.1), f(self.2), f(self.3))
+    }
+
+    pub fn reverse(&self) -> CubicBezier {
+        CubicBezier(self.3, self.2, self.1, self.0)
+    }
+
+    pub fn reflect(&self, line: &Line) -> CubicBezier {
+        // reflection is affine, so mirroring the control points mirrors the curve
+        self.map_controls(|p| line.reflect(p))
+    }
+
+    pub fn offset(&self, dist: f64) -> CubicBezier {
+        // approximately parallel curve dist to the right of the direction of travel, see
+        // Segment::offset
+        // the ends and end tangents are exact (tiller and hanson), but the offset of a cubic
+        // is not a cubic, so the middle can stray for tightly curved or long curves
+        let right = |t: Vector| -t.perp().normalize() * dist;
+        let (n0, n3) = (right(self.tangent_at(0.0)), right(self.tangent_at(1.0)));
+        CubicBezier(self.0 + n0, self.1 + n0, self.2 + n3, self.3 + n3)
+    }
+
+    pub fn sample_points(&self, n: usize) -> Vec<Point> {
+        // n points evenly spaced in t, including both ends
+        assert!(n >= 2);
+        (0..n)
+            .map(|k| self.point_at(k as f64 / (n - 1) as f64))
+            .collect()
+    }
+
+    fn length_to(&self, t: f64) -> f64 {
+        // arc length from the start to t
+        let h = t / LENGTH_PIECES as f64;
+        (0..LENGTH_PIECES)
+            .map(|k| {
+                let a = k as f64 * h;
+                gauss(a, a + h, |u| self.tangent_at(u).norm())
+            })
+            .sum()
+    }
+
+    pub fn length(&self) -> f64 {
+        self.length_to(1.0)
+    }
+
+    pub fn t_at_length(&self, s: f64) -> f64 {
+        // the parameter a distance s along the curve, clamped to the ends
+        let len = self.length();
+        if s <= 0.0 || len == 0.0 {
+            return 0.0;
+        } else if s >= len {
+            return 1.0;
+        }
+
+        let (mut lo, mut hi) = (0.0, 1.0);
+        for _ in 0..60 {
+            let mid = (lo + hi) / 2.0;
+            if self.length_to(mid) < s {
+                lo = mid;
+            } else {
+                hi = mid;
+            }
+        }
+        (lo + hi) / 2.0
+    }
+
+    pub fn closest_t(&self, r: Point) -> f64 {
+        // parameter of the point on the curve closest to r
+        // the best of a coarse sampling is refined by golden section search between its
+        // neighbours, which assumes the samples are fine enough to isolate the minimum
+        let n = 64;
+        let d2 = |t: f64| {
+            let d = self.point_at(t) - r;
+            d.dot(d)
+        };
+        let best = (0..=n)
+            .min_by(|&i, &j| {
+                let (ti, tj) = (i as f64 / n as f64, j as f64 / n as f64);
+                d2(ti).partial_cmp(&d2(tj)).unwrap()
+            })
+            .unwrap();
+
+        let ratio = (5.0f64.sqrt() - 1.0) / 2.0;
+        let mut lo = (best.max(1) - 1) as f64 / n as f64;
+        let mut hi = (best.min(n - 1) + 1) as f64 / n as f64;
+        for _ in 0..80 {
+            let a = hi - ratio * (hi - lo);
+            let b = lo + ratio * (hi - lo);
+            if d2(a) < d2(b) {
+                hi = b;
+            } else {
+                lo = a;
+            }
+        }
+        (lo + hi) / 2.0
+    }
+
+    pub(crate) fn pieces_near(&self, region: &Aabb, tol: f64) -> Vec<Segment> {
+        // flattened pieces of the curve, within tol of it, skipping any part whose control
+        // polygon cannot reach the region
+        let mut res = Vec::new();
+        self.pieces_near_into(region, tol, 0, &mut res);
+        res
+    }
+
+    fn pieces_near_into(&self, region: &Aabb, tol: f64, depth: usize, res: &mut Vec<Segment>) {
+        let controls = self.controls();
+        let hull = Aabb::from_points(controls);
+        let grown = Aabb::from_corners(
+            region.min - Vector::new(tol, tol),
+            region.max + Vector::new(tol, tol),
+        );
+        if !hull.intersects(&grown) {
+            return;
+        }
+        if depth >= MAX_DEPTH || hull_within(&controls, tol) {
+            res.push(Segment::new(self.0, self.3));
+            return;
+        }
+
+        let (a, b) = self.split(0.5);
+        a.pieces_near_into(region, tol, depth + 1, res);
+        b.pieces_near_into(region, tol, depth + 1, res);
+    }
+}
+
+impl From<QuadBezier> for CubicBezier {
+    fn from(q: QuadBezier) -> Self {
+        // degree elevation, tracing the same curve
+        let QuadBezier(p0, p1, p2) = q;
+        CubicBezier(p0, p0.lerp(p1, 2.0 / 3.0), p2.lerp(p1, 2.0 / 3.0), p2)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cubic() -> CubicBezier {
+        CubicBezier(
+            (0.0, 0.0).into(),
+            (1.0, 3.0).into(),
+            (4.0, -2.0).into(),
+            (5.0, 1.0).into(),
+        )
+    }
+
+    fn quad() -> QuadBezier {
+        QuadBezier((0.0, 0.0).into(), (2.0, 4.0).into(), (5.0, -1.0).into())
+    }
+
+    #[test]
+    fn endpoints() {
+        let c = cubic();
+        assert_eq!(c.point_at(0.0), c.0);
+        assert_eq!(c.point_at(1.0), c.3);
+        assert!(c.tangent_at(0.0).eps_eq((c.1 - c.0) * 3.0, 1e-12));
+        assert!(c.tangent_at(1.0).eps_eq((c.3 - c.2) * 3.0, 1e-12));
+
+        let q = quad();
+        assert_eq!(q.point_at(0.0), q.0);
+        assert_eq!(q.point_at(1.0), q.2);
+        assert!(q.tangent_at(0.0).eps_eq((q.1 - q.0) * 2.0, 1e-12));
+
+        // the elevated quadratic is the same curve
+        let e = CubicBezier::from(q);
+        for k in 0..=10 {
+            let t = k as f64 / 10.0;
+            assert!(e.point_at(t).eps_eq(q.point_at(t), 1e-12));
+        }
+    }
+
+    #[test]
+    fn split() {
+        let (c, t) = (cubic(), 0.3);
+        let (a, b) = c.split(t);
+        assert_eq!(a.3, b.0);
+        for k in 0..=10 {
+            let s = k as f64 / 10.0;
+            assert!(a.point_at(s).eps_eq(c.point_at(s * t), 1e-12));
+            assert!(b.point_at(s).eps_eq(c.point_at(t + s * (1.0 - t)), 1e-12));
+        }
+
+        let (q, t) = (quad(), 0.6);
+        let (a, b) = q.split(t);
+        for k in 0..=10 {
+            let s = k as f64 / 10.0;
+            assert!(a.point_at(s).eps_eq(q.point_at(s * t), 1e-12));
+            assert!(b.point_at(s).eps_eq(q.point_at(t + s * (1.0 - t)), 1e-12));
+        }
+    }
+
+    #[test]
+    fn bounding_box() {
+        let samples = |f: &dyn Fn(f64) -> Point| {
+            Aabb::from_points((0..=10_000).map(|k| f(k as f64 / 10_000.0)))
+        };
+
+        let c = cubic();
+        let (exact, sampled) = (c.bounding_box(), samples(&|t| c.point_at(t)));
+        assert!(exact.min.eps_eq(sampled.min, 1e-6));
+        assert!(exact.max.eps_eq(sampled.max, 1e-6));
+
+        let q = quad();
+        let (exact, sampled) = (q.bounding_box(), samples(&|t| q.point_at(t)));
+        assert!(exact.min.eps_eq(sampled.min, 1e-6));
+        assert!(exact.max.eps_eq(sampled.max, 1e-6));
+    }
+
+    #[test]
+    fn flatten() {
+        let c = cubic();
+        for tol in [0.1, 1e-3, 1e-6] {
+            let segs = c.flatten(tol);
+            assert_eq!(segs.first().unwrap().p(), c.0);
+            assert_eq!(segs.last().unwrap().q(), c.3);
+            assert!(segs.windows(2).all(|w| w[0].q() == w[1].p()));
+
+            // every point of the curve is near the polyline
+            for k in 0..=1000 {
+                let p = c.point_at(k as f64 / 1000.0);
+                let d = segs.iter().map(|s| s.dist(p)).fold(f64::INFINITY, f64::min);
+                assert!(d <= tol);
+            }
+        }
+        assert!(c.flatten(1e-3).len() < c.flatten(1e-6).len());
+
+        let q = quad();
+        let segs = q.flatten(1e-4);
+        for k in 0..=1000 {
+            let p = q.point_at(k as f64 / 1000.0);
+            let d = segs.iter().map(|s| s.dist(p)).fold(f64::INFINITY, f64::min);
+            assert!(d <= 1e-4);
+        }
+
+        // a straight curve needs a single segment
+        let line = QuadBezier((0.0, 0.0).into(), (1.0, 1.0).into(), (2.0, 2.0).into());
+        assert_eq!(line.flatten(1e-9).len(), 1);
+    }
+}
@@ -1,12 +1,21 @@
+use std::fmt;
+
+use super::aabb::Aabb;
+use super::area::AreaContribution;
 use super::dist::Dist;
+use super::error::SpaceMathError;
+use super::index::Quadtree;
 use super::intersect::{reflexive_intersect, Intersect, Intersections};
-use super::line::Ray;
-use super::Point;
+use super::line::{Circle, Line, Ray};
+use super::polyline::Polyline;
+use super::{CubicBezier, Point, Triangle};
+use crate::Orient;
 
 #[derive(Clone, Copy, Debug)]
 pub enum Edge {
     Arc(super::Arc),
     Segment(super::Segment),
+    Bezier(CubicBezier),
 }
 
 impl Edge {
@@ -14,6 +23,7 @@ impl Edge {
         match self {
             Edge::Arc(a) => a.p(),
             Edge::Segment(s) => s.p(),
+            Edge::Bezier(b) => b.0,
         }
     }
 
@@ -21,6 +31,7 @@ impl Edge {
         match self {
             Edge::Arc(a) => a.q(),
             Edge::Segment(s) => s.q(),
+            Edge::Bezier(b) => b.3,
         }
     }
 
@@ -38,6 +49,16 @@ impl Edge {
                     .sum::<f64>()
                     / 2.0
             }
+            Edge::Bezier(b) => {
+                // sampled as for arcs, see area_term for the exact value
+                let points = b.sample_points(32);
+                points
+                    .iter()
+                    .zip(points.iter().skip(1))
+                    .map(|(p, q)| p.shoelace(*q))
+                    .sum::<f64>()
+                    / 2.0
+            }
         }
     }
 
@@ -45,6 +66,131 @@ impl Edge {
         match self {
             Edge::Segment(s) => Edge::Segment(s.reverse()),
             Edge::Arc(a) => Edge::Arc(a.reverse()),
+            Edge::Bezier(b) => Edge::Bezier(b.reverse()),
+        }
+    }
+
+    pub fn reflect(self, line: &Line) -> Self {
+        match self {
+            Edge::Segment(s) => Edge::Segment(s.reflect(line)),
+            Edge::Arc(a) => Edge::Arc(a.reflect(line)),
+            Edge::Bezier(b) => Edge::Bezier(b.reflect(line)),
+        }
+    }
+
+    pub fn offset(self, dist: f64) -> Self {
+        // parallel edge dist to the right of the direction of travel, see Segment::offset
+        match self {
+            Edge::Segment(s) => Edge::Segment(s.offset(dist)),
+            Edge::Arc(a) => Edge::Arc(a.offset(dist)),
+            Edge::Bezier(b) => Edge::Bezier(b.offset(dist)),
+        }
+    }
+
+    pub fn length(&self) -> f64 {
+        self.curve().length()
+    }
+
+    pub fn mid(&self) -> Point {
+        // the point halfway along the edge
+        match self {
+            Edge::Segment(s) => s.mid(),
+            Edge::Arc(a) => a.midpoint(),
+            Edge::Bezier(b) => b.point_at(b.t_at_length(b.length() / 2.0)),
+        }
+    }
+
+    pub fn point_at_length(&self, s: f64) -> Point {
+        // the point a distance s along the edge from p, clamped to the ends
+        let len = self.length();
+        if len == 0.0 {
+            return self.p();
+        }
+
+        let t = (s / len).clamp(0.0, 1.0);
+        match self {
+            Edge::Segment(seg) => seg.p().lerp(seg.q(), t),
+            Edge::Arc(a) => {
+                let (p_ang, q_ang) = a.pq_ang_unbounded();
+                a.to_circle().at_ang(p_ang + (q_ang - p_ang) * t)
+            }
+            Edge::Bezier(b) => b.point_at(b.t_at_length(s)),
+        }
+    }
+
+    pub fn split_at(self, points: &[Point]) -> Vec<Self> {
+        // split the edge at the given points, which are assumed to lie on it
+        // points at (or very near) the ends are ignored
+
+        // TODO replace with some tolerance interface
+        let tol = 1e-9;
+        let (p, q) = (self.p(), self.q());
+        let mut points: Vec<Point> = points
+            .iter()
+            .copied()
+            .filter(|r| r.dist(p) > tol && r.dist(q) > tol)
+            .collect();
+
+        match self {
+            Edge::Segment(_) => {
+                points.sort_by(|x, y| x.dist(p).partial_cmp(&y.dist(p)).unwrap());
+                points.dedup_by(|x, y| x.dist(*y) <= tol);
+
+                let mut nodes = vec![p];
+                nodes.extend(points);
+                nodes.push(q);
+
+                nodes
+                    .windows(2)
+                    .map(|pq| Edge::Segment(super::Segment::new(pq[0], pq[1])))
+                    .collect()
+            }
+            Edge::Arc(a) => {
+                // order by angular progress from p in the direction of travel
+                let progress = |ang: f64| {
+                    if a.ccw() {
+                        (ang - a.p_ang()).rem_euclid(std::f64::consts::TAU)
+                    } else {
+                        (a.p_ang() - ang).rem_euclid(std::f64::consts::TAU)
+                    }
+                };
+                let mut angs: Vec<f64> = points.iter().map(|r| (*r - a.center()).ang()).collect();
+                angs.sort_by(|x, y| progress(*x).partial_cmp(&progress(*y)).unwrap());
+                angs.dedup_by(|x, y| (*x - *y).abs() * a.radius() <= tol);
+
+                let mut nodes = vec![a.p_ang()];
+                nodes.extend(angs);
+                nodes.push(a.q_ang());
+
+                nodes
+                    .windows(2)
+                    .map(|pq| {
+                        Edge::Arc(super::Arc::from_center_ang(
+                            a.center(),
+                            a.radius(),
+                            pq[0],
+                            pq[1],
+                            a.ccw(),
+                        ))
+                    })
+                    .collect()
+            }
+            Edge::Bezier(b) => {
+                let mut ts: Vec<f64> = points.iter().map(|r| b.closest_t(*r)).collect();
+                ts.sort_by(|x, y| x.partial_cmp(y).unwrap());
+                ts.dedup_by(|x, y| b.point_at(*x).dist(b.point_at(*y)) <= tol);
+
+                // split off one piece at a time, rescaling the remaining parameters
+                let (mut res, mut rest, mut done) = (Vec::new(), b, 0.0);
+                for t in ts {
+                    let (head, tail) = rest.split((t - done) / (1.0 - done));
+                    res.push(Edge::Bezier(head));
+                    rest = tail;
+                    done = t;
+                }
+                res.push(Edge::Bezier(rest));
+                res
+            }
         }
     }
 
@@ -65,8 +211,48 @@ impl Edge {
 
                 res
             }
+            Edge::Bezier(b) => {
+                // evenly spaced in t rather than in length
+                let intervals = (b.length() / len).max(1.0).round() as usize;
+                b.sample_points(intervals + 1)
+                    .windows(2)
+                    .map(|pq| Edge::Segment(super::Segment::new(pq[0], pq[1])))
+                    .collect()
+            }
+        }
+    }
+
+    pub fn into_segments_tol(self, max_sag: f64) -> Vec<Self> {
+        // splits the edge into segments deviating from it by at most max_sag
+        assert!(max_sag > 0.0);
+        match self {
+            Edge::Segment(s) => vec![Edge::Segment(s)],
+            Edge::Arc(a) => a
+                .flatten_to_tolerance(max_sag)
+                .into_iter()
+                .map(Edge::Segment)
+                .collect(),
+            Edge::Bezier(b) => b.flatten(max_sag).into_iter().map(Edge::Segment).collect(),
         }
     }
+
+    pub fn bounding_box(&self) -> Aabb {
+        // the box around the whole edge, including any bulge of an arc past its ends
+        self.curve().bounding_box()
+    }
+}
+
+fn bezier_intersects(b: &CubicBezier, other: &Edge) -> Intersections {
+    // subdivide and clip: only the parts of the curve near the other edge are flattened,
+    // finely, and their pieces intersected exactly
+    // TODO replace with some tolerance interface
+    let tol = 1e-9;
+    b.pieces_near(&other.bounding_box(), tol)
+        .into_iter()
+        .fold(Intersections::Zero, |acc, s| {
+            acc.combine(Edge::Segment(s).intersects_at(other))
+        })
+        .dedup(tol)
 }
 
 impl Intersect<Edge> for Edge {
@@ -78,6 +264,10 @@ impl Intersect<Edge> for Edge {
             (Arc(x), Segment(y)) => x.intersects_at(&y),
             (Segment(x), Arc(y)) => x.intersects_at(&y),
             (Segment(x), Segment(y)) => x.intersects_at(&y),
+            (Segment(x), Bezier(y)) => x.intersects_at(&y),
+            (Bezier(x), Segment(y)) => y.intersects_at(&x),
+            (Bezier(x), y) => bezier_intersects(&x, &y),
+            (x, Bezier(y)) => bezier_intersects(&y, &x),
         }
     }
 }
@@ -87,11 +277,23 @@ impl Intersect<Ray> for Edge {
         match self {
             Edge::Arc(a) => a.intersects_at(other),
             Edge::Segment(s) => s.intersects_at(other),
+            Edge::Bezier(b) => b.intersects_at(other),
+        }
+    }
+}
+
+impl Intersect<Line> for Edge {
+    fn intersects_at(&self, other: &Line) -> Intersections {
+        match self {
+            Edge::Arc(a) => a.intersects_at(other),
+            Edge::Segment(s) => s.intersects_at(other),
+            Edge::Bezier(b) => b.intersects_at(other),
         }
     }
 }
 
-reflexive_intersect!(Ray, Edge);
+// Ray and Segment intersect edges through their generic Intersect<Line> definitions
+reflexive_intersect!(Line, Edge);
 
 impl From<super::Arc> for Edge {
     fn from(x: super::Arc) -> Self {
@@ -105,15 +307,56 @@ impl From<super::Segment> for Edge {
     }
 }
 
+impl From<CubicBezier> for Edge {
+    fn from(x: CubicBezier) -> Self {
+        Self::Bezier(x)
+    }
+}
+
 impl Dist for Edge {
     fn dist(&self, r: Point) -> f64 {
         match self {
-            &Edge::Arc(ref a) => a.dist(r),
-            &Edge::Segment(ref c) => c.dist(r),
+            Edge::Arc(a) => a.dist(r),
+            Edge::Segment(c) => c.dist(r),
+            Edge::Bezier(b) => b.dist(r),
+        }
+    }
+}
+
+// rule used to decide which points a (possibly self-intersecting) boundary encloses
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FillRule {
+    // inside if a ray from the point crosses the boundary an odd number of times
+    EvenOdd,
+    // inside if the boundary winds around the point a nonzero number of times
+    NonZero,
+}
+
+// where a point lies relative to a boundary, see Boundary::locate
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Containment {
+    Inside,
+    Outside,
+    // within the tolerance of an edge
+    OnBoundary,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum BoundaryError {
+    // the boundaries cross or touch, so neither contains the other
+    Intersecting,
+}
+
+impl fmt::Display for BoundaryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BoundaryError::Intersecting => write!(f, "boundaries intersect"),
         }
     }
 }
 
+impl std::error::Error for BoundaryError {}
+
 // a closed 2d boundary
 #[derive(Clone, Debug)]
 pub struct Boundary {
@@ -123,28 +366,115 @@ pub struct Boundary {
 
 impl Boundary {
     pub fn new<T, U>(edges: T) -> Self
+    where
+        T: IntoIterator<Item = U>,
+        U: Into<Edge>,
+    {
+        // as try_new, panicking on malformed edge loops
+        Self::try_new(edges).unwrap()
+    }
+
+    pub fn try_new<T, U>(edges: T) -> Result<Self, SpaceMathError>
     where
         T: IntoIterator<Item = U>,
         U: Into<Edge>,
     {
         let edges: Vec<_> = edges.into_iter().map(|e| e.into()).collect();
 
-        assert!(!edges.is_empty());
+        if edges.is_empty() {
+            return Err(SpaceMathError::EmptyBoundary);
+        }
 
-        // check the nodes all agree
+        // check the nodes all agree, with nan counted as apart
         // TODO replace with some tolerance interface
-        assert!(edges
+        let apart = |p: Point, q: Point| {
+            let d = p.dist(q);
+            d.is_nan() || d >= 1e-6
+        };
+        let gap = edges
             .iter()
             .zip(edges.iter().skip(1))
-            .all(|(e1, e2)| e1.q().dist(e2.p()) < 1e-6));
+            .position(|(e1, e2)| apart(e1.q(), e2.p()));
+        if let Some(i) = gap {
+            return Err(SpaceMathError::Disconnected(i));
+        }
 
         let start = edges.first().unwrap().p();
         let end = edges.last().unwrap().q();
-        assert!(start.dist(end) < 1e-6);
+        if apart(start, end) {
+            return Err(SpaceMathError::Unclosed);
+        }
 
         let points = edges.iter().map(|e| e.p()).collect();
 
-        Self { edges, points }
+        Ok(Self { edges, points })
+    }
+
+    pub fn from_edges_unordered(edges: Vec<Edge>, tol: f64) -> Result<Self, SpaceMathError> {
+        // chain edges given in any order and direction into a loop, greedily following on
+        // from the end of the chain with any edge starting (or, reversed, ending) within tol
+        // tol should not exceed the gap that new accepts between edges
+        let mut rest: Vec<Edge> = edges;
+        if rest.is_empty() {
+            return Err(SpaceMathError::EmptyBoundary);
+        }
+
+        let mut chain = vec![rest.remove(0)];
+        let start = chain[0].p();
+        loop {
+            let end = chain.last().unwrap().q();
+            if end.dist(start) <= tol {
+                break;
+            }
+
+            let next = rest
+                .iter()
+                .position(|e| e.p().dist(end) <= tol || e.q().dist(end) <= tol)
+                .ok_or(SpaceMathError::Unclosed)?;
+            let e = rest.remove(next);
+            chain.push(if e.p().dist(end) <= tol {
+                e
+            } else {
+                e.reverse()
+            });
+        }
+
+        if !rest.is_empty() {
+            return Err(SpaceMathError::Unchained(rest.len()));
+        }
+        Self::try_new(chain)
+    }
+
+    pub fn from_points(points: &[Point]) -> Self {
+        // a polygon through the points, closed back to the first point
+        assert!(points.len() >= 3);
+
+        let edges = points
+            .iter()
+            .zip(points.iter().cycle().skip(1))
+            .map(|(p, q)| super::Segment::new(*p, *q));
+
+        Self::new(edges)
+    }
+
+    pub fn from_bulge_polyline(vertices: &[(Point, f64)]) -> Self {
+        // a closed polyline in dxf lwpolyline style, each vertex carrying the bulge of the
+        // edge leaving it (see Arc::from_bulge), the last edge running back to the start
+        // zero bulges give straight segments
+        assert!(vertices.len() >= 2);
+
+        let n = vertices.len();
+        let edges: Vec<Edge> = (0..n)
+            .map(|i| {
+                let ((p, bulge), (q, _)) = (vertices[i], vertices[(i + 1) % n]);
+                match super::Arc::from_bulge(p, q, bulge) {
+                    Some(a) => Edge::Arc(a),
+                    None => Edge::Segment(super::Segment::new(p, q)),
+                }
+            })
+            .collect();
+
+        Boundary::new(edges)
     }
 
     pub fn reverse(&mut self) {
@@ -155,10 +485,40 @@ impl Boundary {
             .map(|e| e.clone().reverse())
             .collect();
         self.edges = rev_edges;
+        self.points = self.edges.iter().map(|e| e.p()).collect();
+    }
+
+    pub fn mirror(&self, axis: &Line) -> Boundary {
+        // mirror image of the boundary across the axis
+        // each edge keeps its place in the loop, so the nodes still chain up, but the loop now
+        // runs the other way round: the area is the same in magnitude and opposite in sign
+        Boundary::new(self.edges.iter().map(|e| e.reflect(axis)))
     }
 
     pub fn area(&self) -> f64 {
-        self.edges.iter().map(|e| e.shoelace()).sum()
+        self.edges.iter().map(|e| e.area_term()).sum()
+    }
+
+    pub fn centroid_exact(&self) -> Point {
+        // centroid of the enclosed region, with arcs integrated analytically
+        let (mx, my) = self
+            .edges
+            .iter()
+            .map(|e| e.centroid_term())
+            .fold((0.0, 0.0), |(x, y), (dx, dy)| (x + dx, y + dy));
+        let area = self.area();
+        Point::new(mx / area, my / area)
+    }
+
+    pub fn second_moments(&self) -> (f64, f64, f64) {
+        // (∬ y^2 dA, ∬ x^2 dA, ∬ xy dA) of the enclosed region, about the origin
+        // signed like area: negative for clockwise boundaries
+        self.edges
+            .iter()
+            .map(|e| e.moment_terms())
+            .fold((0.0, 0.0, 0.0), |(xx, yy, xy), (dxx, dyy, dxy)| {
+                (xx + dxx, yy + dyy, xy + dxy)
+            })
     }
 
     pub fn points(&self) -> Vec<Point> {
@@ -174,6 +534,43 @@ impl Boundary {
         self.edges.iter()
     }
 
+    pub fn edge_pairs(&self) -> impl Iterator<Item = (&Edge, &Edge)> {
+        // each edge with the one following it, the last edge paired back with the first
+        self.edges.iter().zip(self.edges.iter().cycle().skip(1))
+    }
+
+    pub fn vertices_with_index(&self) -> impl Iterator<Item = (usize, Point)> + '_ {
+        // the nodes in order, node i being the start of edge i
+        self.points.iter().copied().enumerate()
+    }
+
+    pub(crate) fn flattened_points(&self) -> Vec<Point> {
+        // nodes of the boundary with arcs replaced by short chords, counterclockwise
+        let size = self
+            .edges
+            .iter()
+            .map(|e| match e {
+                Edge::Segment(s) => s.p().dist(s.q()),
+                Edge::Arc(a) => a.radius(),
+                Edge::Bezier(b) => {
+                    let bbox = b.bounding_box();
+                    bbox.width().max(bbox.height())
+                }
+            })
+            .fold(0.0, f64::max);
+        let sag = 1e-4 * size;
+        let mut points: Vec<Point> = self
+            .edges
+            .iter()
+            .flat_map(|e| e.into_segments_tol(sag))
+            .map(|e| e.p())
+            .collect();
+        if self.area() < 0.0 {
+            points.reverse();
+        }
+        points
+    }
+
     pub fn orient_positive(&mut self) {
         // order the edges so the boundary is positively oriented (counterclockwise)
         if self.area() < 0.0 {
@@ -189,55 +586,686 @@ impl Boundary {
 
     pub fn contains(&self, x: Point) -> bool {
         // check whether x is inside the boundary (whether or not boundary is oriented positively)
+        // points on the boundary count as inside, see locate
+        // TODO replace with some tolerance interface
+        self.locate(x, 1e-9) != Containment::Outside
+    }
+
+    pub fn locate(&self, x: Point, tol: f64) -> Containment {
+        // whether x is inside, outside, or within tol of the boundary
+        match self.ray_parity(x, Some(tol)) {
+            None => Containment::OnBoundary,
+            Some(true) => Containment::Inside,
+            Some(false) => Containment::Outside,
+        }
+    }
+
+    fn ray_parity(&self, x: Point, tol: Option<f64>) -> Option<bool> {
+        // whether a ray from x crosses the edges an odd number of times
+        // the count is unreliable for points on an edge, so with tol, none as soon as the pass
+        // finds an edge within tol of x
         let ray = Ray::new(x, 0.1337); // use non-horizontal ray to avoid common edge case of coincident lines
 
         let mut even_crossing = true;
 
         for e in self.edges.iter() {
+            if tol.is_some_and(|tol| e.dist(x) <= tol) {
+                return None;
+            }
             if ray.intersects(e).is_odd() {
                 even_crossing = !even_crossing;
             }
         }
 
-        !even_crossing
+        Some(!even_crossing)
+    }
+
+    pub fn winding_number(&self, x: Point) -> i32 {
+        // number of times the boundary winds around x, positive for counterclockwise
+        // sums the signed angle subtended by each edge, with beziers sampled into chords
+        // an arc subtends the same angle as its chord, plus a full turn its own way round
+        // if x lies between the two, inside the circle on the far side of the chord
+        let mut total = 0.0;
+
+        for e in self.edges.iter() {
+            let points = match e {
+                Edge::Segment(s) => vec![s.p(), s.q()],
+                Edge::Arc(a) => {
+                    let (p, q) = (a.p(), a.q());
+                    let beyond = if a.p_ang() == a.q_ang() {
+                        true
+                    } else {
+                        let side = |r: Point| (q - p).cross(r - p);
+                        side(x) * side(a.midpoint()) > 0.0
+                    };
+                    if beyond && x.dist(a.center()) < a.radius() {
+                        total += if a.ccw() {
+                            std::f64::consts::TAU
+                        } else {
+                            -std::f64::consts::TAU
+                        };
+                    }
+                    vec![p, q]
+                }
+                Edge::Bezier(b) => b.sample_points(64),
+            };
+
+            for pq in points.windows(2) {
+                total += (pq[0] - x).signed_angle(pq[1] - x);
+            }
+        }
+
+        (total / std::f64::consts::TAU).round() as i32
+    }
+
+    pub fn contains_with(&self, x: Point, rule: FillRule) -> bool {
+        // contains under an explicit fill rule
+        // the rules agree unless the boundary crosses itself
+        match rule {
+            FillRule::EvenOdd => self.contains(x),
+            FillRule::NonZero => self.winding_number(x) != 0,
+        }
+    }
+
+    pub fn is_convex(&self) -> bool {
+        // every turn goes the same way, and the boundary turns around only once
+        // arcs are sampled into chords first
+
+        let points: Vec<Point> = self
+            .edges
+            .iter()
+            .flat_map(|e| match e {
+                Edge::Segment(s) => vec![s.p()],
+                Edge::Arc(a) => {
+                    let mut samples = a.sample_points(16);
+                    samples.pop();
+                    samples
+                }
+                Edge::Bezier(b) => {
+                    let mut samples = b.sample_points(16);
+                    samples.pop();
+                    samples
+                }
+            })
+            .collect();
+
+        let n = points.len();
+        if n < 3 {
+            return true;
+        }
+
+        let mut dir = Orient::Zero;
+        let mut turning = 0.0;
+        for i in 0..n {
+            let (p, q, r) = (points[i], points[(i + 1) % n], points[(i + 2) % n]);
+
+            match Triangle::from((p, q, r)).dir() {
+                Orient::Zero => (),
+                d if dir == Orient::Zero => dir = d,
+                d if d != dir => return false,
+                _ => (),
+            }
+
+            let (u, v) = (q - p, r - q);
+            turning += u.cross(v).atan2(u.dot(v));
+        }
+
+        (turning.abs() - std::f64::consts::TAU).abs() < 1e-6
+    }
+
+    pub fn is_simple(&self) -> bool {
+        // no edge meets another, except neighbouring edges at their shared node
+        self.self_intersections().is_empty()
+    }
+
+    pub fn is_valid(&self) -> bool {
+        // simple, and enclosing some area, so area and contains can be trusted
+        // TODO replace with some tolerance interface
+        let tol = 1e-9;
+        self.area().abs() > tol && self.is_simple()
+    }
+
+    pub fn self_intersections(&self) -> Vec<Point> {
+        // points where an edge meets another, other than neighbouring edges at their shared
+        // node, for edges overlapping along a stretch the ends of the overlap are given
+        // only edges with overlapping bounding boxes are tested, paired through build_index
+        // TODO replace with some tolerance interface
+        let tol = 1e-9;
+        let n = self.edges.len();
+        let index = self.build_index();
+
+        let mut res: Vec<Point> = Vec::new();
+        for i in 0..n {
+            let e1 = self.edges[i];
+            for j in index.query_range(&e1.bounding_box()) {
+                if j <= i {
+                    continue;
+                }
+                let e2 = self.edges[j];
+
+                // shared nodes, if the edges are neighbours
+                let mut shared = Vec::new();
+                if j == i + 1 {
+                    shared.push(e1.q());
+                }
+                if i == 0 && j == n - 1 {
+                    shared.push(e1.p());
+                }
+
+                let overlapping = match (e1, e2) {
+                    (Edge::Segment(s1), Edge::Segment(s2)) => s1.overlap_length(&s2, tol) > tol,
+                    _ => false,
+                };
+                let points = if overlapping {
+                    Intersections::Infinite
+                } else {
+                    e1.intersects_at(&e2)
+                };
+                let points = match points {
+                    Intersections::Infinite => {
+                        // the ends of each edge lying on the other
+                        let ends = [(e1.p(), e2), (e1.q(), e2), (e2.p(), e1), (e2.q(), e1)];
+                        ends.iter()
+                            .filter(|(x, e)| e.dist(*x) <= tol)
+                            .map(|&(x, _)| x)
+                            .collect()
+                    }
+                    x => x.into_vec(),
+                };
+
+                for x in points {
+                    let new = shared.iter().chain(res.iter()).all(|s| s.dist(x) > tol);
+                    if new {
+                        res.push(x);
+                    }
+                }
+            }
+        }
+
+        res
+    }
+
+    pub fn split_at_self_intersections(&self) -> Vec<Boundary> {
+        // break a self intersecting boundary into loops at its self intersections, so a
+        // figure eight gives its two lobes, which each keep the direction of travel
+        // a simple boundary gives a copy of itself
+        // TODO replace with some tolerance interface
+        let tol = 1e-9;
+        let crossings = self.self_intersections();
+
+        let pieces = self.edges.iter().flat_map(|e| {
+            let on_edge: Vec<Point> = crossings
+                .iter()
+                .copied()
+                .filter(|x| e.dist(*x) <= tol)
+                .collect();
+            e.split_at(&on_edge)
+        });
+
+        // walk the pieces in order, closing off a loop whenever the walk returns to a node
+        // it has already passed through
+        let mut res = Vec::new();
+        let mut chain: Vec<Edge> = Vec::new();
+        for piece in pieces {
+            let end = piece.q();
+            chain.push(piece);
+            if let Some(k) = chain.iter().position(|e| e.p().dist(end) <= tol) {
+                res.push(Boundary::new(chain.split_off(k)));
+            }
+        }
+        if !chain.is_empty() {
+            // a numerical failure left an open chain, close it as is
+            res.push(Boundary::new(chain));
+        }
+
+        res
     }
 
-    pub fn contains_boundary(&self, other: &Boundary) -> bool {
-        // panic if the boundaries intersect, TODO find better solution
-        assert!(self.intersects(other).is_zero());
+    pub fn inscribed_circle(&self) -> Option<Circle> {
+        // largest circle inside a convex boundary (its chebyshev center)
+        // binary search on the radius, shrinking the polygon by r and checking it is nonempty
+        // arcs are flattened first, so the result is slightly conservative for them
+        // none if the boundary is not convex
+        if !self.is_convex() {
+            return None;
+        }
+
+        let points = self.flattened_points();
+
+        // keep the part of a ccw polygon at least r inside every edge of the original
+        let shrink = |r: f64| {
+            let mut poly = points.clone();
+            for (&p, &q) in points.iter().zip(points.iter().cycle().skip(1)) {
+                let dir = (q - p).normalize();
+                let inside = |x: Point| dir.cross(x - p) - r;
+
+                let mut clipped = Vec::new();
+                for (&x, &y) in poly.iter().zip(poly.iter().cycle().skip(1)) {
+                    let (dx, dy) = (inside(x), inside(y));
+                    if dx >= 0.0 {
+                        clipped.push(x);
+                    }
+                    if (dx >= 0.0) != (dy >= 0.0) {
+                        clipped.push(x + (y - x) * (dx / (dx - dy)));
+                    }
+                }
+
+                poly = clipped;
+                if poly.is_empty() {
+                    break;
+                }
+            }
+            poly
+        };
+
+        let bbox = Aabb::from_points(points.iter().copied());
+        let (mut lo, mut hi) = (0.0, bbox.width().max(bbox.height()));
+        for _ in 0..64 {
+            let mid = (lo + hi) / 2.0;
+            if shrink(mid).is_empty() {
+                hi = mid;
+            } else {
+                lo = mid;
+            }
+        }
+
+        // at the optimal radius the feasible region collapses towards the center
+        let core = shrink(lo);
+        let center = core.iter().fold(Point::origin(), |acc, p| acc + *p) / core.len() as f64;
+        Some(Circle::new(center, lo))
+    }
+
+    pub fn signed_dist(&self, x: Point) -> f64 {
+        // distance to the boundary, negative inside
+        // points within the tolerance of contains count as inside, as there
+        // TODO replace with some tolerance interface
+        let d = self.dist(x);
+        if d <= 1e-9 || self.ray_parity(x, None) == Some(true) {
+            -d
+        } else {
+            d
+        }
+    }
+
+    pub fn sample_sdf(&self, bbox: Aabb, nx: usize, ny: usize) -> Vec<f64> {
+        // signed_dist at the centers of an nx by ny grid of cells covering bbox
+        // row by row from the bottom, so cell (i, j) is at index j * nx + i
+        let (w, h) = (bbox.width() / nx as f64, bbox.height() / ny as f64);
+        let mut res = Vec::with_capacity(nx * ny);
+        for j in 0..ny {
+            for i in 0..nx {
+                let x = bbox.min + Point::new((i as f64 + 0.5) * w, (j as f64 + 0.5) * h);
+                res.push(self.signed_dist(x));
+            }
+        }
+        res
+    }
+
+    pub fn max_inscribed_circle(&self, resolution: f64) -> Circle {
+        // largest circle inside the boundary, centered on its pole of inaccessibility
+        // found by refining grid cells, pruning those that cannot beat the best so far
+        // the radius is within resolution of the optimum
+        let inside_dist = |x: Point| -self.signed_dist(x);
+
+        let bbox = Aabb::from_points(self.edges.iter().flat_map(|e| match e {
+            Edge::Segment(s) => vec![s.p()],
+            Edge::Arc(a) => a.sample_points(64),
+            Edge::Bezier(b) => b.sample_points(64),
+        }));
+        let size = bbox.width().min(bbox.height());
+        if size == 0.0 {
+            return Circle::new(bbox.center(), 0.0);
+        }
+
+        let cell = |center: Point, h: f64| {
+            let d = inside_dist(center);
+            // no point in the cell can be further from the boundary than this
+            let max = d + h * std::f64::consts::SQRT_2;
+            PoleCell { center, h, d, max }
+        };
+
+        let mut queue = std::collections::BinaryHeap::new();
+        let h = size / 2.0;
+        let mut x = bbox.min.x;
+        while x < bbox.max.x {
+            let mut y = bbox.min.y;
+            while y < bbox.max.y {
+                queue.push(cell(Point::new(x + h, y + h), h));
+                y += size;
+            }
+            x += size;
+        }
+
+        let mut best = cell(bbox.center(), 0.0);
+        while let Some(c) = queue.pop() {
+            if c.d > best.d {
+                best = c;
+            }
+
+            if c.max - best.d <= resolution {
+                continue;
+            }
+
+            let h = c.h / 2.0;
+            for (dx, dy) in [(-h, -h), (h, -h), (-h, h), (h, h)] {
+                queue.push(cell(c.center + Point::new(dx, dy), h));
+            }
+        }
+
+        Circle::new(best.center, best.d)
+    }
+
+    pub fn contains_boundary(&self, other: &Boundary) -> Result<bool, BoundaryError> {
+        // whether other lies entirely inside this boundary
+        // boundaries that cross or touch are neither inside nor outside each other, an error
+        if !self.intersects(other).is_zero() {
+            return Err(BoundaryError::Intersecting);
+        }
 
         // note: if boundaries do not intersect arcs don't need to be handled separately
         // also, only need to check one point, if one is contained all are
-        self.contains(other.points[0])
+        Ok(self.contains(other.points[0]))
     }
 
-    pub fn bounding_box(&self) -> (Point, Point) {
-        // finds the (left bottom, right top) corners of the boundary's bounding box
+    pub fn contains_boundary_strict(&self, other: &Boundary) -> bool {
+        // as contains_boundary, panicking if the boundaries intersect
+        self.contains_boundary(other).unwrap()
+    }
 
-        let points = self.points();
+    pub fn cut_by_line(&self, line: &Line) -> (Vec<Polyline>, Vec<Polyline>) {
+        // split the boundary where it crosses the line
+        // returns the pieces on the positive (ax + by > c) and negative sides of the line
+        // pieces lying along the line are dropped
 
-        let x_max = points
-            .iter()
-            .map(|p| p.x)
-            .max_by(|x, y| x.partial_cmp(y).unwrap())
-            .unwrap();
-        let y_max = points
+        // TODO replace with some tolerance interface
+        let tol = 1e-9;
+        let side = |r: Point| {
+            let val = line.a * r.x + line.b * r.y - line.c;
+            if val > tol {
+                1
+            } else if val < -tol {
+                -1
+            } else {
+                0
+            }
+        };
+
+        let pieces: Vec<(Edge, i32)> = self
+            .edges
             .iter()
-            .map(|p| p.y)
-            .max_by(|x, y| x.partial_cmp(y).unwrap())
-            .unwrap();
-        let x_min = points
+            .flat_map(|e| e.split_at(&e.intersects_at(line).into_vec()))
+            .map(|e| (e, side(e.mid())))
+            .collect();
+
+        // start the walk just after a change of side, so no chain wraps past the end
+        let n = pieces.len();
+        let start = (0..n)
+            .find(|&i| pieces[i].1 != pieces[(i + n - 1) % n].1)
+            .unwrap_or(0);
+
+        let mut chains: Vec<(Vec<Edge>, i32)> = Vec::new();
+        for i in 0..n {
+            let (e, s) = pieces[(start + i) % n];
+            match chains.last_mut() {
+                Some((chain, last)) if *last == s => chain.push(e),
+                _ => chains.push((vec![e], s)),
+            }
+        }
+
+        let mut positive = Vec::new();
+        let mut negative = Vec::new();
+        for (chain, s) in chains {
+            match s {
+                1 => positive.push(Polyline::new(chain)),
+                -1 => negative.push(Polyline::new(chain)),
+                _ => (),
+            }
+        }
+
+        (positive, negative)
+    }
+
+    pub fn bounding_box(&self) -> Aabb {
+        // the box around the boundary's nodes
+        Aabb::from_points(self.points.iter().copied())
+    }
+
+    pub fn simplify(&self, tol: f64) -> Boundary {
+        // ramer-douglas-peucker on every run of segments, dropping nodes that lie within tol
+        // of the simplified outline
+        // arcs are kept as they are, and their ends are never dropped
+        let n = self.edges.len();
+        let Some(first_arc) = self
+            .edges
             .iter()
-            .map(|p| p.x)
-            .min_by(|x, y| x.partial_cmp(y).unwrap())
-            .unwrap();
-        let y_min = points
+            .position(|e| !matches!(e, Edge::Segment(_)))
+        else {
+            // a closed polygon, anchored at two nodes which are surely kept: one as far as
+            // possible from the first node, and one as far as possible from that
+            let points = &self.points;
+            let farthest = |from: Point| {
+                (0..points.len())
+                    .max_by(|&i, &j| {
+                        let (di, dj) = (points[i].dist(from), points[j].dist(from));
+                        di.partial_cmp(&dj).unwrap()
+                    })
+                    .unwrap()
+            };
+            let a = farthest(points[0]);
+            let b = farthest(points[a]);
+            let (a, b) = (a.min(b), a.max(b));
+            if a == b {
+                return self.clone();
+            }
+
+            let mut ring = rdp(&points[a..=b], tol);
+            let mut rest: Vec<Point> = points[b..].iter().chain(&points[..=a]).copied().collect();
+            rest = rdp(&rest, tol);
+            ring.extend(&rest[1..rest.len() - 1]);
+            if ring.len() < 3 {
+                // both halves collapsed onto the chord, so keep the node farthest from it
+                // to leave a triangle, in the order it had round the ring
+                let chord = super::Segment::new(points[a], points[b]);
+                let far = (0..points.len())
+                    .filter(|&i| i != a && i != b)
+                    .map(|i| (i, chord.dist(points[i])))
+                    .max_by(|x, y| x.1.partial_cmp(&y.1).unwrap());
+                match far {
+                    Some((c, d)) if d > 0.0 && a < c && c < b => ring.insert(1, points[c]),
+                    Some((c, d)) if d > 0.0 => ring.push(points[c]),
+                    _ => return self.clone(),
+                }
+            }
+            return Boundary::from_points(&ring);
+        };
+
+        // walk round from an arc, collecting the runs of segments between arcs
+        let mut res = Vec::new();
+        let mut run: Vec<Point> = Vec::new();
+        for k in 0..=n {
+            let e = self.edges[(first_arc + k) % n];
+            match e {
+                Edge::Segment(s) if k < n => {
+                    if run.is_empty() {
+                        run.push(s.p());
+                    }
+                    run.push(s.q());
+                }
+                _ => {
+                    if !run.is_empty() {
+                        let kept = rdp(&run, tol);
+                        res.extend(
+                            kept.windows(2)
+                                .map(|pq| Edge::Segment(super::Segment::new(pq[0], pq[1]))),
+                        );
+                        run.clear();
+                    }
+                    if k < n {
+                        res.push(e);
+                    }
+                }
+            }
+        }
+
+        Boundary::new(res)
+    }
+
+    pub fn perimeter(&self) -> f64 {
+        self.edges.iter().map(|e| e.length()).sum()
+    }
+
+    pub fn point_at_arclen(&self, s: f64) -> Point {
+        // the point a distance s along the boundary from its first node
+        // wraps around, so any s (including negative) is valid
+        let mut s = s.rem_euclid(self.perimeter());
+        for e in self.edges.iter() {
+            let len = e.length();
+            if s <= len {
+                return e.point_at_length(s);
+            }
+            s -= len;
+        }
+        self.points[0]
+    }
+
+    pub fn sample_uniform(&self, spacing: f64) -> Vec<Point> {
+        // points spaced evenly by arc length, starting at the first node
+        // the last gap, back round to the start, is shorter unless spacing divides the perimeter
+        assert!(spacing > 0.0);
+
+        // TODO replace with some tolerance interface
+        let n = (self.perimeter() / spacing - 1e-9).ceil().max(1.0) as usize;
+
+        // walk the edges once rather than searching from the start for every point
+        let mut res = Vec::with_capacity(n);
+        let mut start = 0.0;
+        for e in self.edges.iter() {
+            let len = e.length();
+            while res.len() < n && (res.len() as f64 * spacing) < start + len {
+                res.push(e.point_at_length(res.len() as f64 * spacing - start));
+            }
+            start += len;
+        }
+
+        res
+    }
+
+    pub fn raycast(&self, ray: &Ray) -> Option<Point> {
+        // the nearest point where the ray meets the boundary, strictly in front of its origin
+        // edges are visited nearest box first, stopping once no box can beat the best hit
+        let mut boxes: Vec<(f64, &Edge)> = self
+            .edges
             .iter()
-            .map(|p| p.y)
-            .min_by(|x, y| x.partial_cmp(y).unwrap())
-            .unwrap();
+            .map(|e| (e.bounding_box().dist(ray.init), e))
+            .collect();
+        boxes.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
+
+        let mut best: Option<(f64, Point)> = None;
+        for (box_dist, e) in boxes {
+            if best.is_some_and(|(d, _)| box_dist >= d) {
+                break;
+            }
+
+            if let Some(hit) = ray.intersects_at(e).first_hit(ray) {
+                let d = hit.dist(ray.init);
+                if best.is_none_or(|(b, _)| d < b) {
+                    best = Some((d, hit));
+                }
+            }
+        }
+
+        best.map(|(_, p)| p)
+    }
+
+    pub fn build_index(&self) -> Quadtree {
+        // a quadtree over the edge bounding boxes, indexed by edge order
+        // see dist_indexed and contains_indexed
+        let boxes: Vec<Aabb> = self.edges.iter().map(|e| e.bounding_box()).collect();
+        let bounds = boxes.iter().fold(boxes[0], |acc, b| acc.union(b));
+
+        let mut index = Quadtree::new(bounds);
+        for (i, b) in boxes.into_iter().enumerate() {
+            index.insert(b, i);
+        }
+        index
+    }
+
+    pub fn dist_indexed(&self, index: &Quadtree, r: Point) -> f64 {
+        // dist, using an index from build_index to skip far away edges
+        let nearest = index.nearest_by(r, |_, i| self.edges[i].dist(r)).unwrap();
+        self.edges[nearest].dist(r)
+    }
+
+    pub fn contains_indexed(&self, index: &Quadtree, x: Point) -> bool {
+        // contains, using an index from build_index to only test edges near the ray
+        // TODO replace with some tolerance interface
+        if self.dist_indexed(index, x) <= 1e-9 {
+            return true;
+        }
+
+        let ray = Ray::new(x, 0.1337);
+
+        // the ray leaves the indexed region within this distance of x
+        let bounds = index.bounds().union(&Aabb::from_corners(x, x));
+        let reach = bounds.width() + bounds.height() + bounds.dist(x);
+        let end = x + Point::unit(0.1337) * reach;
+
+        let mut even_crossing = true;
+        for i in index.query_range(&Aabb::from_corners(x, end)) {
+            if ray.intersects(&self.edges[i]).is_odd() {
+                even_crossing = !even_crossing;
+            }
+        }
+
+        !even_crossing
+    }
+}
+
+// a square search cell for max_inscribed_circle, ordered by its best possible distance
+#[derive(Clone, Copy)]
+struct PoleCell {
+    center: Point,
+    h: f64,
+    d: f64,
+    max: f64,
+}
+
+impl PartialEq for PoleCell {
+    fn eq(&self, other: &Self) -> bool {
+        self.max == other.max
+    }
+}
+
+impl Eq for PoleCell {}
+
+impl PartialOrd for PoleCell {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for PoleCell {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.max.partial_cmp(&other.max).unwrap()
+    }
+}
 
-        ((x_min, y_min).into(), (x_max, y_max).into())
+fn rdp(points: &[Point], tol: f64) -> Vec<Point> {
+    // ramer-douglas-peucker on an open polyline, always keeping both ends
+    let (first, last) = (points[0], points[points.len() - 1]);
+    let chord = super::Segment::new(first, last);
+    let far = (1..points.len() - 1)
+        .map(|i| (i, chord.dist(points[i])))
+        .max_by(|x, y| x.1.partial_cmp(&y.1).unwrap());
+
+    match far {
+        Some((i, d)) if d > tol => {
+            let mut res = rdp(&points[..=i], tol);
+            res.pop();
+            res.extend(rdp(&points[i..], tol));
+            res
+        }
+        _ => vec![first, last],
     }
 }
 
@@ -302,6 +1330,60 @@ mod tests {
         assert!(bound.contains((0.1, 0.4).into()));
     }
 
+    #[test]
+    fn locate_on_boundary() {
+        use super::super::line::Arc;
+        use super::{Boundary, Containment};
+
+        let square = Boundary::from_points(&[
+            (0.0, 0.0).into(),
+            (1.0, 0.0).into(),
+            (1.0, 1.0).into(),
+            (0.0, 1.0).into(),
+        ]);
+        // on a side, at a corner, and just off a side either way
+        assert_eq!(
+            square.locate((0.5, 0.0).into(), 1e-9),
+            Containment::OnBoundary
+        );
+        assert_eq!(
+            square.locate((1.0, 1.0).into(), 1e-9),
+            Containment::OnBoundary
+        );
+        assert_eq!(square.locate((0.5, 1e-6).into(), 1e-9), Containment::Inside);
+        assert_eq!(
+            square.locate((0.5, -1e-6).into(), 1e-9),
+            Containment::Outside
+        );
+        assert_eq!(
+            square.locate((0.5, -1e-6).into(), 1e-3),
+            Containment::OnBoundary
+        );
+        assert!(square.contains((0.0, 0.3).into()));
+        assert!(square.contains_indexed(&square.build_index(), (0.0, 0.3).into()));
+
+        // a circle, either way round, including on the arc where the ray would graze it
+        for ccw in [true, false] {
+            let circle = Boundary::new(vec![Arc::from_center_ang(
+                (1.0, 1.0).into(),
+                2.0,
+                0.0,
+                0.0,
+                ccw,
+            )]);
+            assert_eq!(circle.locate((1.5, 0.5).into(), 1e-9), Containment::Inside);
+            assert_eq!(circle.locate((2.5, 2.5).into(), 1e-9), Containment::Outside);
+            assert_eq!(
+                circle.locate((3.0, 1.0).into(), 1e-9),
+                Containment::OnBoundary
+            );
+            assert_eq!(
+                circle.locate((1.0, -1.0).into(), 1e-9),
+                Containment::OnBoundary
+            );
+        }
+    }
+
     #[test]
     fn bounds_intersect() {
         use super::super::line::{Arc, Segment};
@@ -355,6 +1437,888 @@ mod tests {
 
         let d_bound = Boundary::new(edges);
 
-        assert!(d_bound.contains_boundary(&square_bound));
+        assert_eq!(d_bound.contains_boundary(&square_bound), Ok(true));
+        assert!(d_bound.contains_boundary_strict(&square_bound));
+        assert_eq!(square_bound.contains_boundary(&d_bound), Ok(false));
+    }
+
+    #[test]
+    fn try_new_errors() {
+        use super::super::error::SpaceMathError;
+        use super::super::line::Segment;
+        use super::Boundary;
+
+        let no_edges: Vec<Segment> = Vec::new();
+        assert_eq!(
+            Boundary::try_new(no_edges).unwrap_err(),
+            SpaceMathError::EmptyBoundary
+        );
+
+        let (a, b, c) = ((0.0, 0.0).into(), (1.0, 0.0).into(), (0.0, 1.0).into());
+        let gap = vec![
+            Segment::new(a, b),
+            Segment::new(b, c),
+            Segment::new((0.0, 2.0).into(), a),
+        ];
+        assert_eq!(
+            Boundary::try_new(gap).unwrap_err(),
+            SpaceMathError::Disconnected(1)
+        );
+
+        let open = vec![Segment::new(a, b), Segment::new(b, c)];
+        assert_eq!(
+            Boundary::try_new(open).unwrap_err(),
+            SpaceMathError::Unclosed
+        );
+
+        let nan = (f64::NAN, 0.0).into();
+        let through_nan = vec![
+            Segment::new(a, nan),
+            Segment::new(nan, c),
+            Segment::new(c, a),
+        ];
+        assert_eq!(
+            Boundary::try_new(through_nan).unwrap_err(),
+            SpaceMathError::Disconnected(0)
+        );
+        let from_nan = vec![Segment::new(nan, b), Segment::new(b, c), Segment::new(c, a)];
+        assert_eq!(
+            Boundary::try_new(from_nan).unwrap_err(),
+            SpaceMathError::Unclosed
+        );
+
+        let closed = vec![Segment::new(a, b), Segment::new(b, c), Segment::new(c, a)];
+        assert_eq!(Boundary::try_new(closed).unwrap().area(), 0.5);
+    }
+
+    #[test]
+    fn unordered_edges() {
+        use super::super::error::SpaceMathError;
+        use super::super::line::Segment;
+        use super::{Boundary, Edge};
+
+        let (a, b, c, d) = (
+            (0.0, 0.0).into(),
+            (1.0, 0.0).into(),
+            (1.0, 1.0).into(),
+            (0.0, 1.0).into(),
+        );
+        let side = |p, q| Edge::Segment(Segment::new(p, q));
+
+        // shuffled, with two sides running the wrong way
+        let scrambled = vec![side(c, d), side(b, a), side(a, d), side(b, c)];
+        let square = Boundary::from_edges_unordered(scrambled, 1e-9).unwrap();
+        assert_eq!(square.edges().count(), 4);
+        assert_eq!(square.area().abs(), 1.0);
+        assert!(square.is_valid());
+        let edges: Vec<&Edge> = square.edges().collect();
+        assert!((0..4).all(|i| edges[i].q() == edges[(i + 1) % 4].p()));
+
+        let open = vec![side(a, b), side(c, d), side(b, c)];
+        assert_eq!(
+            Boundary::from_edges_unordered(open, 1e-9).unwrap_err(),
+            SpaceMathError::Unclosed
+        );
+        let extra = vec![
+            side(a, b),
+            side(c, a),
+            side(b, c),
+            side(d, (2.0, 2.0).into()),
+        ];
+        assert_eq!(
+            Boundary::from_edges_unordered(extra, 1e-9).unwrap_err(),
+            SpaceMathError::Unchained(1)
+        );
+        assert_eq!(
+            Boundary::from_edges_unordered(Vec::new(), 1e-9).unwrap_err(),
+            SpaceMathError::EmptyBoundary
+        );
+    }
+
+    #[test]
+    fn edge_pairs() {
+        use super::super::line::{Arc, Segment};
+        use super::{Boundary, Edge};
+
+        let l_shape = Boundary::from_points(&[
+            (0.0, 0.0).into(),
+            (2.0, 0.0).into(),
+            (2.0, 1.0).into(),
+            (1.0, 1.0).into(),
+            (1.0, 2.0).into(),
+            (0.0, 2.0).into(),
+        ]);
+        assert_eq!(l_shape.edge_pairs().count(), 6);
+        assert!(l_shape.edge_pairs().all(|(e, next)| e.q() == next.p()));
+        let (last, first) = l_shape.edge_pairs().last().unwrap();
+        assert_eq!(
+            (last.q(), first.p()),
+            ((0.0, 0.0).into(), (0.0, 0.0).into())
+        );
+
+        let vertices: Vec<(usize, super::Point)> = l_shape.vertices_with_index().collect();
+        assert_eq!(vertices.len(), 6);
+        assert_eq!(vertices[3], (3, (1.0, 1.0).into()));
+        assert!(l_shape
+            .vertices_with_index()
+            .zip(l_shape.edges())
+            .all(|((_, v), e)| v == e.p()));
+
+        // a single full circle is paired with itself
+        let circle = Boundary::new(vec![Arc::from_center_ang(
+            (0.0, 0.0).into(),
+            1.0,
+            0.0,
+            0.0,
+            true,
+        )]);
+        assert_eq!(circle.edge_pairs().count(), 1);
+
+        let half_disk: Vec<Edge> = vec![
+            Arc::from_center_ang((0.0, 0.0).into(), 1.0, 0.0, std::f64::consts::PI, true).into(),
+            Segment::new((-1.0, 0.0).into(), (1.0, 0.0).into()).into(),
+        ];
+        assert_eq!(Boundary::new(half_disk).edge_pairs().count(), 2);
+    }
+
+    #[test]
+    fn contains_boundary_cases() {
+        use super::{Boundary, BoundaryError};
+
+        let square = |min: f64, max: f64| {
+            Boundary::from_points(&[
+                (min, min).into(),
+                (max, min).into(),
+                (max, max).into(),
+                (min, max).into(),
+            ])
+        };
+        let (outer, inner) = (square(0.0, 4.0), square(1.0, 2.0));
+
+        assert_eq!(outer.contains_boundary(&inner), Ok(true));
+        assert_eq!(inner.contains_boundary(&outer), Ok(false));
+        assert_eq!(inner.contains_boundary(&square(3.0, 5.0)), Ok(false));
+        assert_eq!(
+            outer.contains_boundary(&square(3.0, 5.0)),
+            Err(BoundaryError::Intersecting)
+        );
+        // sharing a corner counts as intersecting
+        assert_eq!(
+            inner.contains_boundary(&square(2.0, 3.0)),
+            Err(BoundaryError::Intersecting)
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn into_segments_tol_negative() {
+        use super::super::line::Segment;
+        use super::Edge;
+
+        // even a segment, which needs no flattening
+        Edge::from(Segment::new((0.0, 0.0).into(), (1.0, 0.0).into())).into_segments_tol(-1.0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn contains_boundary_strict_panics() {
+        use super::Boundary;
+
+        let a = Boundary::from_points(&[(0.0, 0.0).into(), (2.0, 0.0).into(), (0.0, 2.0).into()]);
+        let b = Boundary::from_points(&[(1.0, 1.0).into(), (3.0, 1.0).into(), (1.0, 3.0).into()]);
+        a.contains_boundary_strict(&b);
+    }
+
+    #[test]
+    fn cut_square() {
+        use super::super::line::{Line, Segment};
+        use super::Boundary;
+
+        let edges = vec![
+            Segment::new((0.0, 0.0).into(), (1.0, 0.0).into()),
+            Segment::new((1.0, 0.0).into(), (1.0, 1.0).into()),
+            Segment::new((1.0, 1.0).into(), (0.0, 1.0).into()),
+            Segment::new((0.0, 1.0).into(), (0.0, 0.0).into()),
+        ];
+        let bound = Boundary::new(edges);
+
+        let (above, below) = bound.cut_by_line(&Line::new(0.0, 1.0, 0.5));
+        assert_eq!(above.len(), 1);
+        assert_eq!(below.len(), 1);
+
+        for piece in above.iter().chain(below.iter()) {
+            assert!((piece.p().y - 0.5).abs() < 1e-9);
+            assert!((piece.q().y - 0.5).abs() < 1e-9);
+            assert_eq!(piece.num_edges(), 3);
+            assert!((piece.length() - 2.0).abs() < 1e-9);
+        }
+        assert!(above[0].points().iter().all(|p| p.y >= 0.5));
+        assert!(below[0].points().iter().all(|p| p.y <= 0.5));
+    }
+
+    #[test]
+    fn cut_circle() {
+        use super::super::dist::Dist;
+        use super::super::line::{Arc, Line};
+        use super::Boundary;
+
+        let edges = vec![Arc::from_center_ang((0.0, 0.0).into(), 2.0, 0.0, 0.0, true)];
+        let bound = Boundary::new(edges);
+
+        let (right, left) = bound.cut_by_line(&Line::new(1.0, 0.0, 1.0));
+        assert_eq!(right.len(), 1);
+        assert_eq!(left.len(), 1);
+
+        for piece in right.iter().chain(left.iter()) {
+            assert!((piece.p().x - 1.0).abs() < 1e-9);
+            assert!((piece.q().x - 1.0).abs() < 1e-9);
+        }
+        assert!(right[0].dist((2.0, 0.0).into()) < 1e-9);
+        assert!(left[0].dist((-2.0, 0.0).into()) < 1e-9);
+    }
+
+    #[test]
+    fn triangle_from_points() {
+        use super::Boundary;
+
+        let bound =
+            Boundary::from_points(&[(0.0, 0.0).into(), (4.0, 0.0).into(), (0.0, 3.0).into()]);
+
+        assert_eq!(bound.num_edges(), 3);
+        assert!((bound.area() - 6.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn centroid_exact() {
+        use super::super::dist::Dist;
+        use super::super::line::{Arc, Segment};
+        use super::Boundary;
+        use std::f64::consts::PI;
+
+        let r = 2.0;
+        let edges: Vec<super::Edge> = vec![
+            Arc::from_center_ang((1.0, 1.0).into(), r, 0.0, PI, true).into(),
+            Segment::new((-1.0, 1.0).into(), (3.0, 1.0).into()).into(),
+        ];
+        let mut half_disk = Boundary::new(edges);
+
+        let c = half_disk.centroid_exact();
+        assert!((c.x - 1.0).abs() < 1e-12);
+        assert!((c.y - (1.0 + 4.0 * r / (3.0 * PI))).abs() < 1e-12);
+
+        // orientation does not matter
+        half_disk.reverse();
+        let c = half_disk.centroid_exact();
+        assert!((c.y - (1.0 + 4.0 * r / (3.0 * PI))).abs() < 1e-12);
+
+        let square = Boundary::from_points(&[
+            (0.0, 0.0).into(),
+            (2.0, 0.0).into(),
+            (2.0, 2.0).into(),
+            (0.0, 2.0).into(),
+        ]);
+        assert!(square.centroid_exact().dist((1.0, 1.0).into()) < 1e-12);
+    }
+
+    #[test]
+    fn winding_star() {
+        use super::{Boundary, FillRule};
+        use std::f64::consts::{FRAC_PI_2, TAU};
+
+        // pentagram, visiting every second vertex of a pentagon
+        let points: Vec<super::Point> = (0..5)
+            .map(|i| super::Point::unit(FRAC_PI_2 + (2 * i) as f64 * TAU / 5.0) * 2.0)
+            .collect();
+        let star = Boundary::from_points(&points);
+
+        // the center is wound twice: even-odd calls it outside
+        let center = (0.0, 0.0).into();
+        assert_eq!(star.winding_number(center), 2);
+        assert!(star.contains_with(center, FillRule::NonZero));
+        assert!(!star.contains_with(center, FillRule::EvenOdd));
+
+        // a point in one of the star's tips is wound once
+        let tip = (0.0, 1.5).into();
+        assert_eq!(star.winding_number(tip), 1);
+        assert!(star.contains_with(tip, FillRule::NonZero));
+        assert!(star.contains_with(tip, FillRule::EvenOdd));
+
+        assert_eq!(star.winding_number((3.0, 0.0).into()), 0);
+    }
+
+    #[test]
+    fn winding_circle() {
+        use super::super::line::Arc;
+        use super::Boundary;
+
+        let edges = vec![Arc::from_center_ang((0.0, 0.0).into(), 2.0, 0.0, 0.0, true)];
+        let mut bound = Boundary::new(edges);
+
+        assert_eq!(bound.winding_number((0.5, 1.0).into()), 1);
+        assert_eq!(bound.winding_number((2.5, 1.0).into()), 0);
+
+        bound.reverse();
+        assert_eq!(bound.winding_number((0.5, 1.0).into()), -1);
+    }
+
+    #[test]
+    fn winding_near_arc() {
+        use super::super::line::{Arc, Segment};
+        use super::super::Point;
+        use super::{Boundary, Edge};
+        use std::f64::consts::PI;
+
+        // just inside and just outside a unit circle, between where sampled chords would fall
+        let circle = Boundary::new(vec![Arc::from_center_ang(
+            (0.0, 0.0).into(),
+            1.0,
+            0.0,
+            0.0,
+            true,
+        )]);
+        let inside = Point::unit(PI / 63.0) * 0.9995;
+        assert!(circle.contains(inside));
+        assert_eq!(circle.winding_number(inside), 1);
+        assert_eq!(circle.winding_number(Point::unit(PI / 63.0) * 1.0005), 0);
+
+        // a half disc, with points either side of the arc and of its chord
+        let half: Vec<Edge> = vec![
+            Arc::from_center_ang((0.0, 0.0).into(), 1.0, 0.0, PI, true).into(),
+            Segment::new((-1.0, 0.0).into(), (1.0, 0.0).into()).into(),
+        ];
+        let half = Boundary::new(half);
+        for (x, w) in [
+            (Point::unit(PI / 2.0) * 0.9995, 1),
+            (Point::unit(PI / 2.0) * 1.0005, 0),
+            (Point::new(0.0, 1e-4), 1),
+            (Point::new(0.0, -1e-4), 0),
+        ] {
+            assert_eq!(half.winding_number(x), w);
+            assert_eq!(half.contains(x), w != 0);
+        }
+    }
+
+    #[test]
+    fn convex_and_simple() {
+        use super::Boundary;
+
+        let square = Boundary::from_points(&[
+            (0.0, 0.0).into(),
+            (1.0, 0.0).into(),
+            (1.0, 1.0).into(),
+            (0.0, 1.0).into(),
+        ]);
+        assert!(square.is_convex());
+        assert!(square.is_simple());
+
+        let l_shape = Boundary::from_points(&[
+            (0.0, 0.0).into(),
+            (2.0, 0.0).into(),
+            (2.0, 1.0).into(),
+            (1.0, 1.0).into(),
+            (1.0, 2.0).into(),
+            (0.0, 2.0).into(),
+        ]);
+        assert!(!l_shape.is_convex());
+        assert!(l_shape.is_simple());
+
+        let figure_eight = Boundary::from_points(&[
+            (0.0, 0.0).into(),
+            (1.0, 1.0).into(),
+            (1.0, 0.0).into(),
+            (0.0, 1.0).into(),
+        ]);
+        assert!(!figure_eight.is_convex());
+        assert!(!figure_eight.is_simple());
+    }
+
+    #[test]
+    fn self_intersections() {
+        use super::Boundary;
+
+        let bowtie = Boundary::from_points(&[
+            (0.0, 0.0).into(),
+            (1.0, 1.0).into(),
+            (1.0, 0.0).into(),
+            (0.0, 1.0).into(),
+        ]);
+        let crossings = bowtie.self_intersections();
+        assert_eq!(crossings.len(), 1);
+        assert!(crossings[0].eps_eq((0.5, 0.5).into(), 1e-12));
+        assert!(!bowtie.is_valid());
+
+        // the lobes run opposite ways round
+        let lobes = bowtie.split_at_self_intersections();
+        assert_eq!(lobes.len(), 2);
+        let mut areas: Vec<f64> = lobes.iter().map(|b| b.area()).collect();
+        areas.sort_by(|x, y| x.partial_cmp(y).unwrap());
+        assert!((areas[0] + 0.25).abs() < 1e-12 && (areas[1] - 0.25).abs() < 1e-12);
+        assert!(lobes.iter().all(|b| b.is_valid()));
+
+        // a node touching the far side, and a spike doubling back along itself
+        let touching = Boundary::from_points(&[
+            (0.0, 0.0).into(),
+            (2.0, 0.0).into(),
+            (2.0, 2.0).into(),
+            (1.0, 0.0).into(),
+            (0.0, 2.0).into(),
+        ]);
+        assert_eq!(touching.self_intersections(), vec![(1.0, 0.0).into()]);
+        let spike = Boundary::from_points(&[
+            (0.0, 0.0).into(),
+            (2.0, 0.0).into(),
+            (3.0, 0.0).into(),
+            (1.0, 0.0).into(),
+            (0.0, 1.0).into(),
+        ]);
+        assert_eq!(spike.self_intersections().len(), 2);
+        assert!(!spike.is_simple());
+
+        let square = Boundary::from_points(&[
+            (0.0, 0.0).into(),
+            (1.0, 0.0).into(),
+            (1.0, 1.0).into(),
+            (0.0, 1.0).into(),
+        ]);
+        assert!(square.self_intersections().is_empty());
+        assert!(square.is_valid());
+        assert_eq!(square.split_at_self_intersections().len(), 1);
+    }
+
+    #[test]
+    fn convex_arcs() {
+        use super::super::line::{Arc, Segment};
+        use super::Boundary;
+        use std::f64::consts::PI;
+
+        let half_disk: Vec<super::Edge> = vec![
+            Arc::from_center_ang((0.0, 0.0).into(), 1.0, 0.0, PI, true).into(),
+            Segment::new((-1.0, 0.0).into(), (1.0, 0.0).into()).into(),
+        ];
+        let half_disk = Boundary::new(half_disk);
+        assert!(half_disk.is_convex());
+        assert!(half_disk.is_simple());
+
+        // bite taken out of the top of a square
+        let bitten: Vec<super::Edge> = vec![
+            Segment::new((-1.0, -1.0).into(), (1.0, -1.0).into()).into(),
+            Segment::new((1.0, -1.0).into(), (1.0, 1.0).into()).into(),
+            Arc::from_center_ang((0.0, 1.0).into(), 1.0, 0.0, PI, false).into(),
+            Segment::new((-1.0, 1.0).into(), (-1.0, -1.0).into()).into(),
+        ];
+        let bitten = Boundary::new(bitten);
+        assert!(!bitten.is_convex());
+        assert!(bitten.is_simple());
+    }
+
+    #[test]
+    fn inscribed_circle() {
+        use super::super::dist::Dist;
+        use super::super::line::Arc;
+        use super::Boundary;
+
+        let square = Boundary::from_points(&[
+            (0.0, 0.0).into(),
+            (1.0, 0.0).into(),
+            (1.0, 1.0).into(),
+            (0.0, 1.0).into(),
+        ]);
+        let c = square.inscribed_circle().unwrap();
+        assert!((c.radius - 0.5).abs() < 1e-9);
+        assert!(c.center.dist((0.5, 0.5).into()) < 1e-6);
+
+        // 3-4-5 right triangle, inradius (a + b - c) / 2 = 1
+        let mut tri =
+            Boundary::from_points(&[(0.0, 0.0).into(), (0.0, 3.0).into(), (4.0, 0.0).into()]);
+        let c = tri.inscribed_circle().unwrap();
+        assert!((c.radius - 1.0).abs() < 1e-9);
+        assert!(c.center.dist((1.0, 1.0).into()) < 1e-6);
+        tri.reverse();
+        assert!((tri.inscribed_circle().unwrap().radius - 1.0).abs() < 1e-9);
+
+        let l_shape = Boundary::from_points(&[
+            (0.0, 0.0).into(),
+            (2.0, 0.0).into(),
+            (2.0, 1.0).into(),
+            (1.0, 1.0).into(),
+            (1.0, 2.0).into(),
+            (0.0, 2.0).into(),
+        ]);
+        assert!(l_shape.inscribed_circle().is_none());
+
+        let edges = vec![Arc::from_center_ang((1.0, 0.0).into(), 2.0, 0.0, 0.0, true)];
+        let c = Boundary::new(edges).inscribed_circle().unwrap();
+        assert!((c.radius - 2.0).abs() < 1e-3);
+        assert!(c.center.dist((1.0, 0.0).into()) < 1e-3);
+    }
+
+    #[test]
+    fn max_inscribed_circle() {
+        use super::super::dist::Dist;
+        use super::Boundary;
+
+        // a wide bottom arm and a narrow vertical arm
+        let l_shape = Boundary::from_points(&[
+            (0.0, 0.0).into(),
+            (6.0, 0.0).into(),
+            (6.0, 3.0).into(),
+            (1.0, 3.0).into(),
+            (1.0, 5.0).into(),
+            (0.0, 5.0).into(),
+        ]);
+
+        let resolution = 1e-3;
+        let c = l_shape.max_inscribed_circle(resolution);
+        assert!((c.radius - 1.5).abs() < 2.0 * resolution);
+        assert!(c.center.y < 3.0);
+
+        assert!(l_shape.contains(c.center));
+        assert!(l_shape.dist(c.center) >= c.radius - 1e-9);
+    }
+
+    #[test]
+    fn indexed_queries() {
+        use super::super::dist::Dist;
+        use super::super::Point;
+        use super::Boundary;
+
+        // a star shaped boundary with pseudo-random radii
+        let mut seed: u64 = 12345;
+        let mut rand = || {
+            seed = seed
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            (seed >> 11) as f64 / (1u64 << 53) as f64
+        };
+        let n = 200;
+        let points: Vec<Point> = (0..n)
+            .map(|k| {
+                let ang = std::f64::consts::TAU * k as f64 / n as f64;
+                Point::unit(ang) * (5.0 + 5.0 * rand())
+            })
+            .collect();
+        let bound = Boundary::from_points(&points);
+        let index = bound.build_index();
+        assert_eq!(index.len(), n);
+
+        for _ in 0..200 {
+            let r = Point::new(24.0 * rand() - 12.0, 24.0 * rand() - 12.0);
+
+            let brute = (0..n)
+                .min_by(|&i, &j| {
+                    let (di, dj) = (bound.edges[i].dist(r), bound.edges[j].dist(r));
+                    di.partial_cmp(&dj).unwrap()
+                })
+                .unwrap();
+            // neighbouring edges tie when a node is closest, so compare distances
+            let nearest = index.nearest_by(r, |_, i| bound.edges[i].dist(r)).unwrap();
+            assert_eq!(bound.edges[nearest].dist(r), bound.edges[brute].dist(r));
+
+            assert_eq!(bound.dist_indexed(&index, r), bound.dist(r));
+            assert_eq!(bound.contains_indexed(&index, r), bound.contains(r));
+        }
+    }
+
+    #[test]
+    fn raycast() {
+        use super::super::line::Ray;
+        use super::super::Point;
+        use super::Boundary;
+        use std::f64::consts::PI;
+
+        let square = Boundary::from_points(&[
+            (0.0, 0.0).into(),
+            (4.0, 0.0).into(),
+            (4.0, 4.0).into(),
+            (0.0, 4.0).into(),
+        ]);
+
+        // from outside, the near wall is hit
+        let hit = square.raycast(&Ray::new((-2.0, 1.0).into(), 0.0)).unwrap();
+        assert!(hit.eps_eq(Point::new(0.0, 1.0), 1e-9));
+
+        // from inside, the wall ahead is hit
+        let hit = square
+            .raycast(&Ray::new((1.0, 3.0).into(), PI / 2.0))
+            .unwrap();
+        assert!(hit.eps_eq(Point::new(1.0, 4.0), 1e-9));
+
+        // pointing away
+        assert!(square.raycast(&Ray::new((-2.0, 1.0).into(), PI)).is_none());
+    }
+
+    #[test]
+    fn simplify() {
+        use super::super::Point;
+        use super::{Boundary, Edge};
+
+        // a square with many nodes along each side, starting midway along one, with a little
+        // noise well under the tolerance
+        let mut points = Vec::new();
+        for k in 0..40 {
+            let t = ((k as f64 + 5.0) / 10.0) % 4.0;
+            let wobble = if k % 2 == 0 { 1e-5 } else { -1e-5 };
+            let p = match (t as usize) % 4 {
+                0 => Point::new(t, wobble),
+                1 => Point::new(1.0 + wobble, t - 1.0),
+                2 => Point::new(3.0 - t, 1.0 + wobble),
+                _ => Point::new(wobble, 4.0 - t),
+            };
+            points.push(p);
+        }
+        // exact corners
+        for (k, c) in [
+            (5, (1.0, 0.0)),
+            (15, (1.0, 1.0)),
+            (25, (0.0, 1.0)),
+            (35, (0.0, 0.0)),
+        ] {
+            points[k] = c.into();
+        }
+        let noisy = Boundary::from_points(&points);
+
+        let simple = noisy.simplify(1e-3);
+        assert_eq!(simple.num_edges(), 4);
+        assert!((simple.area() - 1.0).abs() < 1e-12);
+        for c in [(0.0, 0.0), (1.0, 0.0), (1.0, 1.0), (0.0, 1.0)] {
+            assert!(simple.points().contains(&c.into()));
+        }
+
+        // too tight a tolerance keeps the noise
+        assert_eq!(noisy.simplify(1e-6).num_edges(), 40);
+
+        // arcs and their ends survive
+        let rounded = Boundary::from_bulge_polyline(&[
+            ((0.0, 0.0).into(), 0.0),
+            ((1.0, 0.0).into(), 0.0),
+            ((2.0, 0.0).into(), 0.5),
+            ((2.0, 1.0).into(), 0.0),
+            ((1.0, 1.0).into(), 0.0),
+            ((0.0, 1.0).into(), 0.0),
+            ((0.0, 0.5).into(), 0.0),
+        ]);
+        let simple = rounded.simplify(1e-9);
+        assert_eq!(simple.num_edges(), 4);
+        assert_eq!(
+            simple.edges().filter(|e| matches!(e, Edge::Arc(_))).count(),
+            1
+        );
+        assert!((simple.area() - rounded.area()).abs() < 1e-12);
+    }
+
+    #[test]
+    fn simplify_collapse() {
+        use super::super::Point;
+        use super::Boundary;
+
+        // a tolerance wider than the whole square still leaves a triangle, same way round
+        let square = Boundary::from_points(&[
+            (0.0, 0.0).into(),
+            (1.0, 0.0).into(),
+            (1.0, 1.0).into(),
+            (0.0, 1.0).into(),
+        ]);
+        let simple = square.simplify(10.0);
+        assert_eq!(simple.num_edges(), 3);
+        assert!((simple.area() - 0.5).abs() < 1e-12);
+
+        // a thin sliver, every node within tol of the long chord
+        let sliver = Boundary::from_points(&[
+            (0.0, 0.0).into(),
+            (5.0, -1e-4).into(),
+            (10.0, 0.0).into(),
+            (5.0, 2e-4).into(),
+        ]);
+        let simple = sliver.simplify(1e-3);
+        assert_eq!(simple.num_edges(), 3);
+        assert!(simple.area() > 0.0);
+        assert!(simple.points().contains(&Point::new(5.0, 2e-4)));
+    }
+
+    #[test]
+    fn sample_uniform() {
+        use super::super::Point;
+        use super::Boundary;
+
+        let square = Boundary::from_points(&[
+            (0.0, 0.0).into(),
+            (1.0, 0.0).into(),
+            (1.0, 1.0).into(),
+            (0.0, 1.0).into(),
+        ]);
+        assert_eq!(square.perimeter(), 4.0);
+
+        let points = square.sample_uniform(0.25);
+        assert_eq!(points.len(), 16);
+        for (i, p) in points.iter().enumerate() {
+            assert!(p.eps_eq(square.point_at_arclen(i as f64 * 0.25), 1e-12));
+        }
+        assert!(points[6].eps_eq(Point::new(1.0, 0.5), 1e-12));
+
+        // a spacing not dividing the perimeter leaves a short closing gap
+        assert_eq!(square.sample_uniform(0.3).len(), 14);
+
+        // wrap around the closing edge, and before the start
+        assert!(square
+            .point_at_arclen(4.5)
+            .eps_eq(Point::new(0.5, 0.0), 1e-12));
+        assert!(square
+            .point_at_arclen(-0.25)
+            .eps_eq(Point::new(0.0, 0.25), 1e-12));
+    }
+
+    #[test]
+    fn from_bulge_polyline() {
+        use super::super::dist::Dist;
+        use super::super::{Arc, Point};
+        use super::{Boundary, Edge};
+        use std::f64::consts::PI;
+
+        // a unit square with its top edge replaced by a half circle bulging upward
+        let bound = Boundary::from_bulge_polyline(&[
+            ((0.0, 0.0).into(), 0.0),
+            ((1.0, 0.0).into(), 0.0),
+            ((1.0, 1.0).into(), 1.0),
+            ((0.0, 1.0).into(), 0.0),
+        ]);
+        assert_eq!(bound.num_edges(), 4);
+        assert!((bound.area() - (1.0 + PI / 8.0)).abs() < 1e-12);
+
+        let Edge::Arc(top) = bound.edges[2] else {
+            panic!("expected an arc");
+        };
+        let expected =
+            Arc::from_endpoints((1.0, 1.0).into(), (0.0, 1.0).into(), 0.5, true).unwrap();
+        assert!(top.center().dist(expected.center()) < 1e-12);
+        assert!(top.center().dist(Point::new(0.5, 1.0)) < 1e-12);
+    }
+
+    #[test]
+    fn mirror() {
+        use super::super::line::Line;
+        use super::super::Point;
+        use super::Boundary;
+
+        // a trapezoid off to the right of the y axis, with one side bulging out
+        let bound = Boundary::from_bulge_polyline(&[
+            ((1.0, 0.0).into(), 0.0),
+            ((3.0, 0.0).into(), 0.4),
+            ((3.0, 1.0).into(), 0.0),
+            ((1.0, 2.0).into(), 0.0),
+        ]);
+        let y_axis = Line::new(1.0, 0.0, 0.0);
+        let mirrored = bound.mirror(&y_axis);
+
+        assert!(bound.area() > 0.0);
+        assert!((mirrored.area() + bound.area()).abs() < 1e-12);
+
+        let flip = |p: Point| Point::new(-p.x, p.y);
+        assert!(mirrored
+            .centroid_exact()
+            .eps_eq(flip(bound.centroid_exact()), 1e-12));
+        for p in [
+            Point::new(1.5, 1.5),
+            Point::new(3.1, 0.5),
+            Point::new(2.0, 2.0),
+        ] {
+            assert_eq!(mirrored.contains(flip(p)), bound.contains(p));
+        }
+    }
+
+    #[test]
+    fn bezier_edge() {
+        use super::super::area::AreaContribution;
+        use super::super::dist::Dist;
+        use super::super::intersect::Intersect;
+        use super::super::line::Line;
+        use super::super::{CubicBezier, Point, Segment};
+        use super::{Boundary, Edge};
+
+        // a square whose top side is an s-shaped bezier, running right to left
+        let top = CubicBezier(
+            (2.0, 2.0).into(),
+            (1.5, 3.0).into(),
+            (0.8, 1.5).into(),
+            (0.0, 2.0).into(),
+        );
+        let bound = Boundary::new(vec![
+            Edge::Segment(Segment::new((0.0, 0.0).into(), (2.0, 0.0).into())),
+            Edge::Segment(Segment::new((2.0, 0.0).into(), (2.0, 2.0).into())),
+            Edge::Bezier(top),
+            Edge::Segment(Segment::new((0.0, 2.0).into(), (0.0, 0.0).into())),
+        ]);
+
+        let flat = Boundary::new(bound.edges().flat_map(|e| e.into_segments_tol(1e-7)));
+        assert!((bound.area() - flat.area()).abs() < 1e-6);
+        assert!((bound.area() - 4.0).abs() > 1e-3);
+        let c = bound.centroid_exact();
+        assert!(c.eps_eq(flat.centroid_exact(), 1e-6));
+        let (m, f) = (bound.second_moments(), flat.second_moments());
+        assert!((m.0 - f.0).abs() < 1e-5 && (m.1 - f.1).abs() < 1e-5 && (m.2 - f.2).abs() < 1e-5);
+
+        // the sampled shoelace agrees with the exact term
+        let e = Edge::Bezier(top);
+        assert!((e.shoelace() - e.area_term()).abs() < 1e-3);
+
+        // the bulge above y = 2 near the right is inside, the dip near the left is not
+        assert!(bound.contains((1.5, 2.2).into()));
+        assert!(!bound.contains((0.35, 1.95).into()));
+        assert!(bound.contains((0.35, 1.85).into()));
+        assert!(bound.dist(Point::new(1.0, 5.0)) > 2.0);
+        assert!(e.dist(top.point_at(0.3)) < 1e-9);
+
+        // the horizontal line y = 2 meets the curve at both ends and once between
+        let hits = Line::new(0.0, 1.0, 2.0).intersects_at(&e);
+        assert_eq!(hits.len(), 3);
+        assert!(hits
+            .iter()
+            .all(|p| (p.y - 2.0).abs() < 1e-9 && e.dist(*p) < 1e-9));
+        assert!(hits.iter().any(|p| 0.7 < p.x && p.x < 0.8));
+
+        // round trips and splits
+        let mut rev = bound.clone();
+        rev.reverse();
+        assert!((rev.area() + bound.area()).abs() < 1e-12);
+        let pieces = e.split_at(&[top.point_at(0.25), top.point_at(0.75)]);
+        assert_eq!(pieces.len(), 3);
+        let total: f64 = pieces.iter().map(|p| p.length()).sum();
+        assert!((total - e.length()).abs() < 1e-9);
+        assert!(pieces[1].p().eps_eq(top.point_at(0.25), 1e-9));
+    }
+
+    #[test]
+    fn signed_dist() {
+        use super::super::aabb::Aabb;
+        use super::super::dist::Dist;
+        use super::super::{Arc, Point};
+        use super::Boundary;
+
+        let (center, r) = (Point::new(1.0, -1.0), 2.0);
+        let circle = Boundary::new(vec![Arc::from_center_ang(center, r, 0.0, 0.0, true)]);
+        let analytic = |p: Point| center.dist(p) - r;
+
+        let bbox = Aabb::from_corners((-2.0, -4.0).into(), (4.0, 2.0).into());
+        let (nx, ny) = (12, 9);
+        let sdf = circle.sample_sdf(bbox, nx, ny);
+        assert_eq!(sdf.len(), nx * ny);
+        for j in 0..ny {
+            for i in 0..nx {
+                let p = Point::new(
+                    -2.0 + (i as f64 + 0.5) * 0.5,
+                    -4.0 + (j as f64 + 0.5) * 6.0 / 9.0,
+                );
+                assert!((sdf[j * nx + i] - analytic(p)).abs() < 1e-9);
+            }
+        }
+
+        // the sign flips right at the boundary
+        for ang in [0.3, 1.7, 4.0] {
+            let on = center + Point::unit(ang) * r;
+            let out = center + Point::unit(ang) * (r + 1e-7);
+            let inside = center + Point::unit(ang) * (r - 1e-7);
+            assert!(circle.signed_dist(out) > 0.0);
+            assert!(circle.signed_dist(inside) < 0.0);
+            assert!(circle.signed_dist(on).abs() < 1e-9);
+        }
     }
 }
@@ -0,0 +1,229 @@
+use std::fmt;
+
+use super::boundary::{Boundary, Edge};
+use super::{Arc, CubicBezier, Point, Segment};
+
+// compact binary encoding for boundaries
+// u32 edge count, then per edge a u8 tag followed by little-endian f64 fields
+//   segment: p.x p.y q.x q.y
+//   arc:     center.x center.y radius p_ang q_ang, then a u8 ccw flag
+//   bezier:  the four control points, x then y for each
+
+const SEGMENT_TAG: u8 = 0;
+const ARC_TAG: u8 = 1;
+const BEZIER_TAG: u8 = 2;
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum DecodeError {
+    // the input ended in the middle of a field
+    Truncated,
+    // an edge tag was not recognised
+    BadTag(u8),
+    // the input had bytes left over after the last edge
+    TrailingBytes,
+    // a boundary needs at least one edge
+    Empty,
+    // arc angles must lie in [0, 2pi] and radii be positive, see Arc::try_from_center_ang
+    BadArc,
+    // consecutive edges do not share endpoints, or the boundary is not closed
+    Disconnected,
+}
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DecodeError::Truncated => write!(f, "input ended unexpectedly"),
+            DecodeError::BadTag(t) => write!(f, "unknown edge tag {}", t),
+            DecodeError::TrailingBytes => write!(f, "unexpected bytes after the last edge"),
+            DecodeError::Empty => write!(f, "boundary has no edges"),
+            DecodeError::BadArc => write!(f, "arc angle or radius out of range"),
+            DecodeError::Disconnected => write!(f, "edges do not form a closed boundary"),
+        }
+    }
+}
+
+impl std::error::Error for DecodeError {}
+
+struct Reader<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, n: usize) -> Result<&'a [u8], DecodeError> {
+        if self.bytes.len() < n {
+            return Err(DecodeError::Truncated);
+        }
+        let (head, tail) = self.bytes.split_at(n);
+        self.bytes = tail;
+        Ok(head)
+    }
+
+    fn u8(&mut self) -> Result<u8, DecodeError> {
+        Ok(self.take(1)?[0])
+    }
+
+    fn u32(&mut self) -> Result<u32, DecodeError> {
+        Ok(u32::from_le_bytes(self.take(4)?.try_into().unwrap()))
+    }
+
+    fn f64(&mut self) -> Result<f64, DecodeError> {
+        Ok(f64::from_le_bytes(self.take(8)?.try_into().unwrap()))
+    }
+
+    fn point(&mut self) -> Result<Point, DecodeError> {
+        Ok(Point::new(self.f64()?, self.f64()?))
+    }
+}
+
+impl Boundary {
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut res = Vec::new();
+        res.extend((self.num_edges() as u32).to_le_bytes());
+
+        for e in self.edges() {
+            let fields = match e {
+                Edge::Segment(s) => {
+                    res.push(SEGMENT_TAG);
+                    vec![s.p().x, s.p().y, s.q().x, s.q().y]
+                }
+                Edge::Arc(a) => {
+                    res.push(ARC_TAG);
+                    vec![a.center().x, a.center().y, a.radius(), a.p_ang(), a.q_ang()]
+                }
+                Edge::Bezier(b) => {
+                    res.push(BEZIER_TAG);
+                    b.controls().iter().flat_map(|p| [p.x, p.y]).collect()
+                }
+            };
+
+            for f in fields {
+                res.extend(f.to_le_bytes());
+            }
+
+            if let Edge::Arc(a) = e {
+                res.push(a.ccw() as u8);
+            }
+        }
+
+        res
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Boundary, DecodeError> {
+        let mut reader = Reader { bytes };
+
+        let n = reader.u32()?;
+        if n == 0 {
+            return Err(DecodeError::Empty);
+        }
+
+        let mut edges = Vec::new();
+        for _ in 0..n {
+            let edge = match reader.u8()? {
+                SEGMENT_TAG => Edge::Segment(Segment::new(reader.point()?, reader.point()?)),
+                ARC_TAG => {
+                    let center = reader.point()?;
+                    let radius = reader.f64()?;
+                    let p_ang = reader.f64()?;
+                    let q_ang = reader.f64()?;
+                    let ccw = reader.u8()? != 0;
+
+                    Edge::Arc(
+                        Arc::try_from_center_ang(center, radius, p_ang, q_ang, ccw)
+                            .map_err(|_| DecodeError::BadArc)?,
+                    )
+                }
+                BEZIER_TAG => Edge::Bezier(CubicBezier(
+                    reader.point()?,
+                    reader.point()?,
+                    reader.point()?,
+                    reader.point()?,
+                )),
+                t => return Err(DecodeError::BadTag(t)),
+            };
+            edges.push(edge);
+        }
+
+        if !reader.bytes.is_empty() {
+            return Err(DecodeError::TrailingBytes);
+        }
+
+        // the edge count was checked above, so any failure is a gap between edges
+        Boundary::try_new(edges).map_err(|_| DecodeError::Disconnected)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn d_bound() -> Boundary {
+        let edges: Vec<Edge> = vec![
+            Arc::from_center_ang(
+                (0.0, 0.0).into(),
+                2.0,
+                (3.0 * std::f64::consts::PI) / 2.0,
+                std::f64::consts::PI / 2.0,
+                true,
+            )
+            .into(),
+            Segment::new((0.0, 2.0).into(), (0.0, -2.0).into()).into(),
+        ];
+        Boundary::new(edges)
+    }
+
+    #[test]
+    fn bytes_round_trip() {
+        let bound = d_bound();
+        let bytes = bound.to_bytes();
+        let decoded = Boundary::from_bytes(&bytes).unwrap();
+
+        assert_eq!(decoded.to_bytes(), bytes);
+        assert_eq!(decoded.num_edges(), 2);
+        assert!((decoded.area() - bound.area()).abs() < 1e-9);
+    }
+
+    #[test]
+    fn bytes_truncated() {
+        let bytes = d_bound().to_bytes();
+
+        for n in 0..bytes.len() {
+            assert_eq!(
+                Boundary::from_bytes(&bytes[..n]).unwrap_err(),
+                DecodeError::Truncated
+            );
+        }
+    }
+
+    #[test]
+    fn bytes_invalid() {
+        let mut bytes = d_bound().to_bytes();
+        bytes[4] = 7;
+        assert_eq!(
+            Boundary::from_bytes(&bytes).unwrap_err(),
+            DecodeError::BadTag(7)
+        );
+
+        assert_eq!(
+            Boundary::from_bytes(&[0, 0, 0, 0]).unwrap_err(),
+            DecodeError::Empty
+        );
+
+        // a nan node can't be shown to meet its neighbours
+        let nan = Boundary::from_points(&[(0.0, 0.0).into(), (1.0, 0.0).into(), (1.0, 1.0).into()]);
+        let mut bytes = nan.to_bytes();
+        // the first segment's end, after the count, its tag and its start
+        bytes[4 + 1 + 16..4 + 1 + 24].copy_from_slice(&f64::NAN.to_le_bytes());
+        assert_eq!(
+            Boundary::from_bytes(&bytes).unwrap_err(),
+            DecodeError::Disconnected
+        );
+
+        let mut bytes = d_bound().to_bytes();
+        // the arc's radius, after the count, its tag and its center
+        bytes[4 + 1 + 16..4 + 1 + 24].copy_from_slice(&(-2.0f64).to_le_bytes());
+        assert_eq!(
+            Boundary::from_bytes(&bytes).unwrap_err(),
+            DecodeError::BadArc
+        );
+    }
+}
@@ -0,0 +1,152 @@
+// convex hulls of point sets, and measures taken over them
+
+use super::dist::Dist;
+use super::triangle::orient;
+use super::Point;
+use crate::Orient;
+
+pub fn hull(points: &[Point]) -> Vec<Point> {
+    // the corners of the convex hull, counterclockwise, by andrew's monotone chain
+    // colinear points along the sides are dropped, O(n log n)
+    let mut pts = points.to_vec();
+    pts.sort_by(|a, b| {
+        a.x.partial_cmp(&b.x)
+            .unwrap()
+            .then(a.y.partial_cmp(&b.y).unwrap())
+    });
+    pts.dedup();
+    if pts.len() < 3 {
+        return pts;
+    }
+
+    // lower chain left to right, then upper chain right to left
+    let mut res: Vec<Point> = Vec::with_capacity(pts.len() + 1);
+    for pass in [pts.clone(), pts.iter().rev().copied().collect()] {
+        let floor = res.len();
+        for p in pass {
+            while res.len() >= floor + 2
+                && orient(res[res.len() - 2], res[res.len() - 1], p) != Orient::Positive
+            {
+                res.pop();
+            }
+            res.push(p);
+        }
+        // the last point of each chain starts the other
+        res.pop();
+    }
+    res
+}
+
+pub fn hull_area(points: &[Point]) -> f64 {
+    // area of the convex hull, zero for fewer than three points not on a line
+    let h = hull(points);
+    if h.len() < 3 {
+        return 0.0;
+    }
+    h.iter()
+        .zip(h.iter().cycle().skip(1))
+        .map(|(p, q)| p.shoelace(*q))
+        .sum::<f64>()
+        / 2.0
+}
+
+pub fn hull_diameter(points: &[Point]) -> f64 {
+    // greatest distance between any two of the points, zero for fewer than two
+    // the farthest pair are both hull corners, and rotating calipers visit every antipodal
+    // pair of corners in O(n) once the hull is known
+    let h = hull(points);
+    let n = h.len();
+    if n < 2 {
+        return 0.0;
+    } else if n == 2 {
+        return h[0].dist(h[1]);
+    }
+
+    // twice the area of the triangle from the edge (a, b) to c, so the height above the edge
+    let height = |a: Point, b: Point, c: Point| (b - a).cross(c - a);
+
+    let mut best: f64 = 0.0;
+    let mut j = 1;
+    for i in 0..n {
+        let (a, b) = (h[i], h[(i + 1) % n]);
+        // advance the caliper to the corner farthest from edge i
+        while height(a, b, h[(j + 1) % n]) > height(a, b, h[j]) {
+            j = (j + 1) % n;
+        }
+        best = best.max(a.dist(h[j])).max(b.dist(h[j]));
+    }
+    best
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid_square() -> Vec<Point> {
+        // a 3 by 3 square sampled on a grid, with the interior and sides filled in
+        (0..=6)
+            .flat_map(|i| (0..=6).map(move |j| Point::new(i as f64 * 0.5, j as f64 * 0.5)))
+            .collect()
+    }
+
+    #[test]
+    fn hull_corners() {
+        let h = hull(&grid_square());
+        assert_eq!(h.len(), 4);
+        for c in [(0.0, 0.0), (3.0, 0.0), (3.0, 3.0), (0.0, 3.0)] {
+            assert!(h.contains(&c.into()));
+        }
+
+        let area: f64 = h
+            .iter()
+            .zip(h.iter().cycle().skip(1))
+            .map(|(p, q)| p.shoelace(*q))
+            .sum();
+        assert!(area > 0.0);
+
+        assert_eq!(hull(&[(1.0, 1.0).into(), (1.0, 1.0).into()]).len(), 1);
+        assert_eq!(
+            hull(&[(0.0, 0.0).into(), (1.0, 1.0).into(), (2.0, 2.0).into()]),
+            vec![Point::new(0.0, 0.0), Point::new(2.0, 2.0)]
+        );
+    }
+
+    #[test]
+    fn square_area_diameter() {
+        let square = grid_square();
+        assert_eq!(hull_area(&square), 9.0);
+        assert!((hull_diameter(&square) - 3.0 * std::f64::consts::SQRT_2).abs() < 1e-12);
+    }
+
+    #[test]
+    fn known_hull() {
+        // a hexagon around some interior points, area from the shoelace formula by hand
+        let points: Vec<Point> = vec![
+            (0.0, 0.0).into(),
+            (4.0, 0.0).into(),
+            (6.0, 2.0).into(),
+            (4.0, 4.0).into(),
+            (0.0, 4.0).into(),
+            (-2.0, 2.0).into(),
+            (1.0, 1.0).into(),
+            (3.0, 2.5).into(),
+            (2.0, 2.0).into(),
+        ];
+        assert_eq!(hull_area(&points), 24.0);
+        assert_eq!(hull_diameter(&points), 8.0);
+
+        // against every pair, on a rounder set
+        let ring: Vec<Point> = (0..50)
+            .map(|k| {
+                let k = k as f64;
+                Point::unit(k * 0.7) * (3.0 + (k * 1.3).sin())
+            })
+            .collect();
+        let brute = ring
+            .iter()
+            .flat_map(|p| ring.iter().map(move |q| p.dist(*q)))
+            .fold(0.0, f64::max);
+        assert!((hull_diameter(&ring) - brute).abs() < 1e-12);
+        assert_eq!(hull_diameter(&[(1.0, 1.0).into()]), 0.0);
+    }
+}
@@ -0,0 +1,222 @@
+// a common interface over the kinds of edge, for code that only needs to walk along one
+
+use super::aabb::Aabb;
+use super::boundary::Edge;
+use super::dist::Dist;
+use super::{Arc, CubicBezier, Point, Segment, Vector};
+
+// a curve parametrized by t in [0, 1], from its start at t = 0 to its end at t = 1
+pub trait Curve {
+    fn point_at(&self, t: f64) -> Point;
+
+    // derivative with respect to t, not normalized
+    fn tangent_at(&self, t: f64) -> Vector;
+
+    fn length(&self) -> f64;
+
+    // the same curve, run the other way
+    fn reverse(&self) -> Self
+    where
+        Self: Sized;
+
+    fn bounding_box(&self) -> Aabb;
+
+    fn sample(&self, n: usize) -> Vec<Point> {
+        // n points evenly spaced in t, including both ends
+        assert!(n >= 2);
+        (0..n)
+            .map(|k| self.point_at(k as f64 / (n - 1) as f64))
+            .collect()
+    }
+}
+
+impl Curve for Segment {
+    fn point_at(&self, t: f64) -> Point {
+        self.p().lerp(self.q(), t)
+    }
+
+    fn tangent_at(&self, _t: f64) -> Vector {
+        self.q() - self.p()
+    }
+
+    fn length(&self) -> f64 {
+        self.p().dist(self.q())
+    }
+
+    fn reverse(&self) -> Segment {
+        Segment::reverse(*self)
+    }
+
+    fn bounding_box(&self) -> Aabb {
+        Aabb::from_corners(self.p(), self.q())
+    }
+}
+
+impl Curve for Arc {
+    // t is proportional to the angle swept, and so to the length along the arc
+
+    fn point_at(&self, t: f64) -> Point {
+        let (p_ang, q_ang) = self.pq_ang_unbounded();
+        self.to_circle().at_ang(p_ang + (q_ang - p_ang) * t)
+    }
+
+    fn tangent_at(&self, t: f64) -> Vector {
+        let (p_ang, q_ang) = self.pq_ang_unbounded();
+        let ang = p_ang + (q_ang - p_ang) * t;
+        Vector::unit(ang).perp() * (self.radius() * (q_ang - p_ang))
+    }
+
+    fn length(&self) -> f64 {
+        self.arc_length()
+    }
+
+    fn reverse(&self) -> Arc {
+        Arc::reverse(self)
+    }
+
+    fn bounding_box(&self) -> Aabb {
+        // the ends, and the extremes of the circle that the arc passes through
+        let extremes = [(1.0, 0.0), (0.0, 1.0), (-1.0, 0.0), (0.0, -1.0)]
+            .into_iter()
+            .map(|d| self.center() + Vector::from(d) * self.radius())
+            .filter(|&r| self.bounds_contain(r));
+        Aabb::from_points([self.p(), self.q()].into_iter().chain(extremes))
+    }
+}
+
+impl Curve for CubicBezier {
+    fn point_at(&self, t: f64) -> Point {
+        CubicBezier::point_at(self, t)
+    }
+
+    fn tangent_at(&self, t: f64) -> Vector {
+        CubicBezier::tangent_at(self, t)
+    }
+
+    fn length(&self) -> f64 {
+        CubicBezier::length(self)
+    }
+
+    fn reverse(&self) -> CubicBezier {
+        CubicBezier::reverse(self)
+    }
+
+    fn bounding_box(&self) -> Aabb {
+        CubicBezier::bounding_box(self)
+    }
+
+    fn sample(&self, n: usize) -> Vec<Point> {
+        self.sample_points(n)
+    }
+}
+
+impl Edge {
+    pub fn curve(&self) -> &dyn Curve {
+        match self {
+            Edge::Segment(s) => s,
+            Edge::Arc(a) => a,
+            Edge::Bezier(b) => b,
+        }
+    }
+}
+
+impl Curve for Edge {
+    fn point_at(&self, t: f64) -> Point {
+        self.curve().point_at(t)
+    }
+
+    fn tangent_at(&self, t: f64) -> Vector {
+        self.curve().tangent_at(t)
+    }
+
+    fn length(&self) -> f64 {
+        self.curve().length()
+    }
+
+    fn reverse(&self) -> Edge {
+        Edge::reverse(*self)
+    }
+
+    fn bounding_box(&self) -> Aabb {
+        self.curve().bounding_box()
+    }
+
+    fn sample(&self, n: usize) -> Vec<Point> {
+        self.curve().sample(n)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn close(a: Point, b: Point) -> bool {
+        a.eps_eq(b, 1e-9)
+    }
+
+    #[test]
+    fn segment_curve() {
+        let s = Segment::new((1.0, 2.0).into(), (4.0, -2.0).into());
+        assert!(close(Curve::point_at(&s, 0.5), s.mid()));
+        assert_eq!(Curve::length(&s), 5.0);
+        assert_eq!(Curve::reverse(&s).p(), s.reverse().p());
+        assert_eq!(Curve::sample(&s, 2), vec![s.p(), s.q()]);
+        assert_eq!(Curve::bounding_box(&s), Edge::Segment(s).bounding_box());
+    }
+
+    #[test]
+    fn arc_curve() {
+        use std::f64::consts::FRAC_PI_2;
+
+        for ccw in [true, false] {
+            let a = Arc::from_center_ang((1.0, 1.0).into(), 2.0, 5.0, 1.0, ccw);
+            assert!(close(Curve::point_at(&a, 0.0), a.p()));
+            assert!(close(Curve::point_at(&a, 1.0), a.q()));
+            assert!(close(Curve::point_at(&a, 0.5), a.midpoint()));
+            assert_eq!(Curve::length(&a), a.arc_length());
+
+            let samples = Curve::sample(&a, 9);
+            for (x, y) in samples.iter().zip(a.sample_points(9)) {
+                assert!(close(*x, y));
+            }
+
+            // the unit tangent matches, and the speed is the length
+            let tangent = Curve::tangent_at(&a, 0.0);
+            assert!(tangent.normalize().eps_eq(a.tangent_at(a.p()), 1e-9));
+            assert!((tangent.norm() - a.arc_length()).abs() < 1e-9);
+
+            let r = Curve::reverse(&a);
+            assert!(close(r.p(), a.q()) && r.ccw() != a.ccw());
+        }
+
+        // a quarter arc reaches the top of its circle
+        let a = Arc::from_center_ang((0.0, 0.0).into(), 1.0, 0.0, FRAC_PI_2, true);
+        let bbox = Curve::bounding_box(&a);
+        assert!(close(bbox.min, (0.0, 0.0).into()) && close(bbox.max, (1.0, 1.0).into()));
+    }
+
+    #[test]
+    fn bezier_curve() {
+        let b = CubicBezier(
+            (0.0, 0.0).into(),
+            (1.0, 3.0).into(),
+            (4.0, -2.0).into(),
+            (5.0, 1.0).into(),
+        );
+        let e = Edge::Bezier(b);
+        assert_eq!(e.point_at(0.3), b.point_at(0.3));
+        assert_eq!(e.tangent_at(0.3), b.tangent_at(0.3));
+        assert_eq!(Curve::length(&e), b.length());
+        assert_eq!(Curve::bounding_box(&e), b.bounding_box());
+        assert_eq!(e.sample(5), b.sample_points(5));
+        assert!(close(Curve::reverse(&e).p(), b.3));
+
+        // the length is the limit of finer and finer chords
+        let chords: f64 = b
+            .sample_points(10_001)
+            .windows(2)
+            .map(|pq| pq[0].dist(pq[1]))
+            .sum();
+        assert!((b.length() - chords).abs() < 1e-6);
+    }
+}
@@ -1,5 +1,6 @@
+use super::aabb::Aabb;
 use super::intersect::Intersect;
-use super::{Arc, Circle, Line, Point, Ray, Segment};
+use super::{Arc, Circle, CubicBezier, Ellipse, Line, Point, Ray, Segment};
 
 pub trait Dist {
     fn dist(&self, r: Point) -> f64;
@@ -14,24 +15,13 @@ impl Dist for Line {
 
 impl Dist for Segment {
     fn dist(&self, r: Point) -> f64 {
-        // if out of bounds, choose the closest node
-        let projected = self.to_line().projected(r);
-
-        if self.bounds_contain(projected) {
-            projected.dist(r)
-        } else {
-            r.dist(self.p()).min(r.dist(self.q()))
-        }
+        self.clamp(r).dist(r)
     }
 }
 
 impl Dist for Ray {
     fn dist(&self, r: Point) -> f64 {
-        if self.bounds_contain(r) {
-            self.to_line().dist(r)
-        } else {
-            r.dist(self.init)
-        }
+        self.clamp(r).dist(r)
     }
 }
 
@@ -42,6 +32,63 @@ impl Dist for Circle {
     }
 }
 
+impl Dist for Ellipse {
+    fn dist(&self, r: Point) -> f64 {
+        // distance to the curve, after eberly's robust method: by symmetry work in the
+        // first quadrant of the ellipse's own frame, where the closest point is found by
+        // bisecting for the root of a monotone function
+        let local = self.frame_coords(r);
+        let (y0, y1) = (local.x.abs(), local.y.abs());
+        let (e0, e1) = (self.semi_major, self.semi_minor);
+
+        if y1 == 0.0 || e1 == 0.0 {
+            // on the major axis (or the ellipse is flat), the closest point is the end of the
+            // axis unless the point lies inside the evolute's reach
+            let (numer, denom) = (e0 * y0, e0 * e0 - e1 * e1);
+            if numer < denom {
+                let x = numer / denom;
+                return Point::new(e0 * x - y0, e1 * (1.0 - x * x).sqrt() - y1).norm();
+            }
+            return Point::new(e0 - y0, y1).norm();
+        }
+        if y0 == 0.0 {
+            return (y1 - e1).abs();
+        }
+
+        let (z0, z1) = (y0 / e0, y1 / e1);
+        let g = z0 * z0 + z1 * z1 - 1.0;
+        if g == 0.0 {
+            return 0.0;
+        }
+
+        let r0 = (e0 / e1).powi(2);
+        let n0 = r0 * z0;
+        let (mut s0, mut s1) = (z1 - 1.0, 0.0);
+        if g > 0.0 {
+            s1 = Point::new(n0, z1).norm() - 1.0;
+        }
+        let mut s = s0;
+        for _ in 0..200 {
+            s = (s0 + s1) / 2.0;
+            if s == s0 || s == s1 {
+                break;
+            }
+            let f = (n0 / (s + r0)).powi(2) + (z1 / (s + 1.0)).powi(2) - 1.0;
+            if f > 0.0 {
+                s0 = s;
+            } else if f < 0.0 {
+                s1 = s;
+            } else {
+                break;
+            }
+        }
+
+        let x0 = r0 * y0 / (s + r0);
+        let x1 = y1 / (s + 1.0);
+        Point::new(x0 - y0, x1 - y1).norm()
+    }
+}
+
 impl Dist for Arc {
     fn dist(&self, r: Point) -> f64 {
         if self.bounds_contain(r) {
@@ -51,3 +98,16 @@ impl Dist for Arc {
         }
     }
 }
+
+impl Dist for CubicBezier {
+    fn dist(&self, r: Point) -> f64 {
+        self.point_at(self.closest_t(r)).dist(r)
+    }
+}
+
+impl Dist for Aabb {
+    fn dist(&self, r: Point) -> f64 {
+        // zero inside the box
+        r.dist(r.clamp(self.min, self.max))
+    }
+}
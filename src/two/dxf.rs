@@ -0,0 +1,142 @@
+use std::fmt::Write;
+
+use super::boundary::{Boundary, Edge};
+
+fn write_group(d: &mut String, code: u16, value: impl std::fmt::Display) {
+    // dxf is a flat list of (group code, value) pairs, one per line
+    writeln!(d, "{}", code).unwrap();
+    writeln!(d, "{}", value).unwrap();
+}
+
+fn write_edge(d: &mut String, e: &Edge) {
+    match e {
+        Edge::Segment(s) => {
+            write_group(d, 0, "LINE");
+            write_group(d, 8, 0);
+            write_group(d, 10, s.p().x);
+            write_group(d, 20, s.p().y);
+            write_group(d, 11, s.q().x);
+            write_group(d, 21, s.q().y);
+        }
+        Edge::Arc(a) => {
+            // dxf arcs always run counterclockwise from the start angle to the end angle,
+            // so clockwise arcs are written from q to p
+            let (start, end) = if a.ccw() {
+                (a.p_ang(), a.q_ang())
+            } else {
+                (a.q_ang(), a.p_ang())
+            };
+
+            write_group(d, 0, "ARC");
+            write_group(d, 8, 0);
+            write_group(d, 10, a.center().x);
+            write_group(d, 20, a.center().y);
+            write_group(d, 40, a.radius());
+            write_group(d, 50, start.to_degrees());
+            write_group(d, 51, end.to_degrees());
+        }
+        Edge::Bezier(b) => {
+            // a cubic bezier is a planar degree 3 spline with a single span: four control
+            // points and knots 0 0 0 0 1 1 1 1
+            write_group(d, 0, "SPLINE");
+            write_group(d, 8, 0);
+            write_group(d, 70, 8);
+            write_group(d, 71, 3);
+            write_group(d, 72, 8);
+            write_group(d, 73, 4);
+            for knot in [0, 0, 0, 0, 1, 1, 1, 1] {
+                write_group(d, 40, knot);
+            }
+            for p in b.controls() {
+                write_group(d, 10, p.x);
+                write_group(d, 20, p.y);
+            }
+        }
+    }
+}
+
+impl Boundary {
+    pub fn to_dxf_entities(&self) -> String {
+        // one LINE, ARC or SPLINE entity per edge, on layer 0
+        // only the entities themselves, to be placed in the ENTITIES section of a dxf file
+        let mut d = String::new();
+        for e in self.edges() {
+            write_edge(&mut d, e);
+        }
+        d
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::two::{Arc, Segment};
+    use std::f64::consts::PI;
+
+    fn groups(d: &str) -> Vec<(u16, String)> {
+        let lines: Vec<&str> = d.lines().collect();
+        lines
+            .chunks(2)
+            .map(|pair| (pair[0].parse().unwrap(), pair[1].to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn dxf_segment() {
+        let mut d = String::new();
+        write_edge(
+            &mut d,
+            &Segment::new((1.0, 2.0).into(), (3.5, -4.0).into()).into(),
+        );
+
+        let expected = [
+            (0, "LINE"),
+            (8, "0"),
+            (10, "1"),
+            (20, "2"),
+            (11, "3.5"),
+            (21, "-4"),
+        ];
+        let found = groups(&d);
+        assert_eq!(found.len(), expected.len());
+        for ((code, value), (e_code, e_value)) in found.iter().zip(expected) {
+            assert_eq!((*code, value.as_str()), (e_code, e_value));
+        }
+    }
+
+    #[test]
+    fn dxf_arc() {
+        let ccw = Arc::from_center_ang((1.0, 1.0).into(), 2.0, 0.0, PI / 2.0, true);
+        for a in [ccw, ccw.reverse()] {
+            let mut d = String::new();
+            write_edge(&mut d, &a.into());
+
+            let found = groups(&d);
+            let codes: Vec<u16> = found.iter().map(|g| g.0).collect();
+            assert_eq!(codes, vec![0, 8, 10, 20, 40, 50, 51]);
+            assert_eq!(found[0].1, "ARC");
+            assert_eq!(found[4].1, "2");
+
+            // the same counterclockwise sweep either way round
+            let start: f64 = found[5].1.parse().unwrap();
+            let end: f64 = found[6].1.parse().unwrap();
+            assert!(start.abs() < 1e-9);
+            assert!((end - 90.0).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn dxf_boundary() {
+        let bound = Boundary::new(vec![
+            Edge::from(Arc::from_center_ang((0.0, 0.0).into(), 1.0, 0.0, PI, true)),
+            Edge::from(Segment::new((-1.0, 0.0).into(), (1.0, 0.0).into())),
+        ]);
+        let d = bound.to_dxf_entities();
+        let kinds: Vec<String> = groups(&d)
+            .into_iter()
+            .filter(|g| g.0 == 0)
+            .map(|g| g.1)
+            .collect();
+        assert_eq!(kinds, vec!["ARC", "LINE"]);
+    }
+}
@@ -0,0 +1,36 @@
+// errors from the checked constructors, see Boundary::try_new and Arc::try_from_center_ang
+
+use std::fmt;
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum SpaceMathError {
+    // a boundary needs at least one edge
+    EmptyBoundary,
+    // the edge at this index ends away from the start of the next
+    Disconnected(usize),
+    // the last edge ends away from the start of the first
+    Unclosed,
+    // this many edges were left over after closing a loop, see Boundary::from_edges_unordered
+    Unchained(usize),
+    // arc angles must lie in [0, 2pi]
+    AngleOutOfRange(f64),
+    // arc radii must be positive
+    BadRadius(f64),
+}
+
+impl fmt::Display for SpaceMathError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SpaceMathError::EmptyBoundary => write!(f, "boundary has no edges"),
+            SpaceMathError::Disconnected(i) => {
+                write!(f, "edge {} does not end where the next begins", i)
+            }
+            SpaceMathError::Unclosed => write!(f, "boundary does not end where it begins"),
+            SpaceMathError::Unchained(n) => write!(f, "{} edges are not part of the loop", n),
+            SpaceMathError::AngleOutOfRange(a) => write!(f, "arc angle {} out of range", a),
+            SpaceMathError::BadRadius(r) => write!(f, "arc radius {} is not positive", r),
+        }
+    }
+}
+
+impl std::error::Error for SpaceMathError {}
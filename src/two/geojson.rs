@@ -0,0 +1,196 @@
+use std::fmt;
+
+use serde_json::{json, Value};
+
+use super::boundary::{Boundary, Edge};
+use super::{Point, Segment};
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum GeoJsonError {
+    // the object's "type" member was missing or not the expected geometry
+    WrongType,
+    // a position was not an array of two or more numbers
+    BadCoordinate(String),
+    // polygon rings must have at least three distinct points
+    TooFewPoints,
+    // polygon rings must end on their starting point
+    Unclosed,
+    // interior rings are not supported
+    Holes,
+}
+
+impl fmt::Display for GeoJsonError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GeoJsonError::WrongType => write!(f, "unexpected geojson geometry type"),
+            GeoJsonError::BadCoordinate(s) => write!(f, "bad position '{}'", s),
+            GeoJsonError::TooFewPoints => {
+                write!(f, "polygon ring needs at least three distinct points")
+            }
+            GeoJsonError::Unclosed => write!(f, "polygon ring is not closed"),
+            GeoJsonError::Holes => write!(f, "polygons with holes are not supported"),
+        }
+    }
+}
+
+impl std::error::Error for GeoJsonError {}
+
+fn position(p: Point) -> Value {
+    json!([p.x, p.y])
+}
+
+fn parse_position(v: &Value) -> Result<Point, GeoJsonError> {
+    // any altitude (or further elements) is ignored
+    let bad = || GeoJsonError::BadCoordinate(v.to_string());
+    let coords = v.as_array().filter(|c| c.len() >= 2).ok_or_else(bad)?;
+    let x = coords[0].as_f64().ok_or_else(bad)?;
+    let y = coords[1].as_f64().ok_or_else(bad)?;
+    Ok(Point::new(x, y))
+}
+
+fn coordinates<'a>(v: &'a Value, kind: &str) -> Result<&'a Value, GeoJsonError> {
+    // the coordinates of a geometry object, checking its type
+    if v.get("type").and_then(Value::as_str) != Some(kind) {
+        return Err(GeoJsonError::WrongType);
+    }
+    v.get("coordinates").ok_or(GeoJsonError::WrongType)
+}
+
+impl Point {
+    pub fn to_geojson(&self) -> Value {
+        json!({ "type": "Point", "coordinates": position(*self) })
+    }
+
+    pub fn from_geojson(v: &Value) -> Result<Point, GeoJsonError> {
+        parse_position(coordinates(v, "Point")?)
+    }
+}
+
+impl Boundary {
+    pub fn to_geojson_polygon(&self, tol: f64) -> Value {
+        // arcs are flattened into segments deviating from them by at most tol
+        // the ring is counterclockwise (the right hand rule), repeating the first point
+        let mut bound = self.clone();
+        bound.orient_positive();
+
+        let mut ring = vec![position(bound.points()[0])];
+        for e in bound.edges().flat_map(|e| e.into_segments_tol(tol)) {
+            ring.push(position(e.q()));
+        }
+
+        json!({ "type": "Polygon", "coordinates": [ring] })
+    }
+
+    pub fn from_geojson_polygon(v: &Value) -> Result<Boundary, GeoJsonError> {
+        // parses a single-ring Polygon into a boundary of segments, in either winding
+        let rings = coordinates(v, "Polygon")?
+            .as_array()
+            .ok_or(GeoJsonError::WrongType)?;
+        let ring = match rings.as_slice() {
+            [ring] => ring.as_array().ok_or(GeoJsonError::WrongType)?,
+            [] => return Err(GeoJsonError::TooFewPoints),
+            _ => return Err(GeoJsonError::Holes),
+        };
+
+        let points = ring
+            .iter()
+            .map(parse_position)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        if points.len() < 4 {
+            return Err(GeoJsonError::TooFewPoints);
+        }
+        if points.first() != points.last() {
+            return Err(GeoJsonError::Unclosed);
+        }
+        let mut distinct: Vec<Point> = Vec::new();
+        for p in points.iter() {
+            if !distinct.contains(p) {
+                distinct.push(*p);
+            }
+        }
+        if distinct.len() < 3 {
+            return Err(GeoJsonError::TooFewPoints);
+        }
+
+        let edges = points
+            .windows(2)
+            .map(|pq| Edge::Segment(Segment::new(pq[0], pq[1])));
+        Ok(Boundary::new(edges))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn geojson_round_trip() {
+        // clockwise, so the export must flip it
+        let tri = Boundary::from_points(&[(0.0, 0.0).into(), (0.0, 3.0).into(), (4.0, 0.0).into()]);
+        assert!(tri.area() < 0.0);
+
+        let v = tri.to_geojson_polygon(1e-3);
+        assert_eq!(v["type"], "Polygon");
+        let ring = v["coordinates"][0].as_array().unwrap();
+        assert_eq!(ring.len(), 4);
+        assert_eq!(ring[0], ring[3]);
+
+        let back = Boundary::from_geojson_polygon(&v).unwrap();
+        assert_eq!(back.num_edges(), 3);
+        assert!((back.area() - 6.0).abs() < 1e-12);
+
+        let text = v.to_string();
+        let parsed: Value = serde_json::from_str(&text).unwrap();
+        assert_eq!(
+            Boundary::from_geojson_polygon(&parsed).unwrap().area(),
+            back.area()
+        );
+    }
+
+    #[test]
+    fn geojson_point() {
+        let p = Point::new(1.5, -2.0);
+        let v = p.to_geojson();
+        assert_eq!(v, json!({ "type": "Point", "coordinates": [1.5, -2.0] }));
+        assert_eq!(Point::from_geojson(&v), Ok(p));
+
+        let with_altitude = json!({ "type": "Point", "coordinates": [1.5, -2.0, 10.0] });
+        assert_eq!(Point::from_geojson(&with_altitude), Ok(p));
+    }
+
+    #[test]
+    fn geojson_errors() {
+        let open = json!({ "type": "Polygon", "coordinates": [[[0, 0], [1, 0], [1, 1], [0, 1]]] });
+        assert_eq!(
+            Boundary::from_geojson_polygon(&open).err(),
+            Some(GeoJsonError::Unclosed)
+        );
+
+        let degenerate =
+            json!({ "type": "Polygon", "coordinates": [[[0, 0], [0, 0], [0, 0], [0, 0]]] });
+        assert_eq!(
+            Boundary::from_geojson_polygon(&degenerate).err(),
+            Some(GeoJsonError::TooFewPoints)
+        );
+
+        let line =
+            json!({ "type": "Polygon", "coordinates": [[[0, 0], [1, 0], [0, 0], [1, 0], [0, 0]]] });
+        assert_eq!(
+            Boundary::from_geojson_polygon(&line).err(),
+            Some(GeoJsonError::TooFewPoints)
+        );
+
+        let point = json!({ "type": "Point", "coordinates": [0, 0] });
+        assert_eq!(
+            Boundary::from_geojson_polygon(&point).err(),
+            Some(GeoJsonError::WrongType)
+        );
+
+        let bad = json!({ "type": "Point", "coordinates": ["a", 0] });
+        assert!(matches!(
+            Point::from_geojson(&bad),
+            Err(GeoJsonError::BadCoordinate(_))
+        ));
+    }
+}
@@ -0,0 +1,192 @@
+// a quadtree over bounding boxes, for range and nearest queries on many small features
+
+use super::aabb::Aabb;
+use super::dist::Dist;
+use super::Point;
+
+// split a node once it holds more items than this
+const CAPACITY: usize = 8;
+const MAX_DEPTH: usize = 16;
+
+// each item is a box with an index into whatever the caller keeps alongside
+// items are stored in the smallest node containing their box, so boxes straddling a split
+// line stay in the parent, and boxes outside the root bounds stay in the root
+#[derive(Clone, Debug)]
+pub struct Quadtree {
+    bounds: Aabb,
+    depth: usize,
+    items: Vec<(Aabb, usize)>,
+    children: Option<Box<[Quadtree; 4]>>,
+}
+
+impl Quadtree {
+    pub fn new(bounds: Aabb) -> Self {
+        Self::with_depth(bounds, 0)
+    }
+
+    fn with_depth(bounds: Aabb, depth: usize) -> Self {
+        Quadtree {
+            bounds,
+            depth,
+            items: Vec::new(),
+            children: None,
+        }
+    }
+
+    fn fits(&self, b: &Aabb) -> bool {
+        self.bounds.contains(b.min) && self.bounds.contains(b.max)
+    }
+
+    fn child_for(&mut self, b: &Aabb) -> Option<&mut Quadtree> {
+        self.children
+            .as_mut()
+            .and_then(|c| c.iter_mut().find(|c| c.fits(b)))
+    }
+
+    fn split(&mut self) {
+        let (min, max, mid) = (self.bounds.min, self.bounds.max, self.bounds.center());
+        let quad =
+            |a: Point, b: Point| Quadtree::with_depth(Aabb::from_corners(a, b), self.depth + 1);
+        self.children = Some(Box::new([
+            quad(min, mid),
+            quad(Point::new(mid.x, min.y), Point::new(max.x, mid.y)),
+            quad(mid, max),
+            quad(Point::new(min.x, mid.y), Point::new(mid.x, max.y)),
+        ]));
+
+        // push down whatever now fits in a child
+        for (b, i) in std::mem::take(&mut self.items) {
+            self.insert(b, i);
+        }
+    }
+
+    pub fn insert(&mut self, b: Aabb, i: usize) {
+        if let Some(child) = self.child_for(&b) {
+            child.insert(b, i);
+            return;
+        }
+
+        self.items.push((b, i));
+        if self.children.is_none() && self.items.len() > CAPACITY && self.depth < MAX_DEPTH {
+            self.split();
+        }
+    }
+
+    pub fn bounds(&self) -> Aabb {
+        self.bounds
+    }
+
+    pub fn len(&self) -> usize {
+        let below: usize = self
+            .children
+            .iter()
+            .flat_map(|c| c.iter())
+            .map(|c| c.len())
+            .sum();
+        self.items.len() + below
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn query_range(&self, range: &Aabb) -> Vec<usize> {
+        // indices of every item whose box touches range
+        let mut res = Vec::new();
+        self.query_into(range, &mut res);
+        res
+    }
+
+    fn query_into(&self, range: &Aabb, res: &mut Vec<usize>) {
+        res.extend(
+            self.items
+                .iter()
+                .filter(|(b, _)| b.intersects(range))
+                .map(|&(_, i)| i),
+        );
+
+        for child in self.children.iter().flat_map(|c| c.iter()) {
+            if child.bounds.intersects(range) {
+                child.query_into(range, res);
+            }
+        }
+    }
+
+    pub fn nearest(&self, p: Point) -> Option<usize> {
+        // the item whose box is closest to p
+        self.nearest_by(p, |b, _| b.dist(p))
+    }
+
+    pub fn nearest_by<F: Fn(&Aabb, usize) -> f64>(&self, p: Point, dist: F) -> Option<usize> {
+        // the item minimizing dist, which must be no less than the distance from p to the
+        // item's box so that far away nodes can be skipped
+        let mut best = None;
+        self.nearest_into(p, &dist, &mut best);
+        best.map(|(_, i)| i)
+    }
+
+    fn nearest_into<F: Fn(&Aabb, usize) -> f64>(
+        &self,
+        p: Point,
+        dist: &F,
+        best: &mut Option<(f64, usize)>,
+    ) {
+        let beats = |d: f64, best: &Option<(f64, usize)>| best.is_none_or(|(b, _)| d < b);
+
+        for &(b, i) in self.items.iter() {
+            if beats(b.dist(p), best) {
+                let d = dist(&b, i);
+                if beats(d, best) {
+                    *best = Some((d, i));
+                }
+            }
+        }
+
+        if let Some(children) = self.children.as_ref() {
+            // closest quadrants first, so the rest are more likely to be skipped
+            let mut order: Vec<(f64, &Quadtree)> =
+                children.iter().map(|c| (c.bounds.dist(p), c)).collect();
+            order.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
+
+            for (d, child) in order {
+                if beats(d, best) {
+                    child.nearest_into(p, dist, best);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn quadtree_queries() {
+        let mut tree = Quadtree::new(Aabb::from_corners((0.0, 0.0).into(), (10.0, 10.0).into()));
+        let boxes: Vec<Aabb> = (0..100)
+            .map(|k| {
+                let p = Point::new((k % 10) as f64 + 0.25, (k / 10) as f64 + 0.25);
+                Aabb::from_corners(p, p + Point::new(0.5, 0.5))
+            })
+            .collect();
+        for (i, b) in boxes.iter().enumerate() {
+            tree.insert(*b, i);
+        }
+        // one item outside the root bounds
+        tree.insert(
+            Aabb::from_corners((12.0, 12.0).into(), (13.0, 13.0).into()),
+            100,
+        );
+        assert_eq!(tree.len(), 101);
+
+        let range = Aabb::from_corners((2.0, 3.0).into(), (4.5, 4.3).into());
+        let mut found = tree.query_range(&range);
+        found.sort();
+        assert_eq!(found, vec![32, 33, 34, 42, 43, 44]);
+
+        assert_eq!(tree.nearest((5.5, 7.4).into()), Some(75));
+        assert_eq!(tree.nearest((20.0, 20.0).into()), Some(100));
+        assert_eq!(Quadtree::new(range).nearest((0.0, 0.0).into()), None);
+    }
+}
@@ -1,5 +1,5 @@
 use super::shift::Shift;
-use super::{Arc, Circle, Line, Point, Ray, Segment};
+use super::{Arc, Circle, CubicBezier, Ellipse, Line, Point, Ray, Segment, Vector};
 
 use super::dist::Dist;
 
@@ -56,12 +56,14 @@ impl From<usize> for Count {
 }
 
 // used instead of a vec to avoid allocations in simple cases
+// Infinite is for coincident geometry (the same line or circle), which meets everywhere
 #[derive(Clone, Debug, PartialEq)]
 pub enum Intersections {
     Zero,
     One(Point),
     Two(Point, Point),
     Many(Vec<Point>),
+    Infinite,
 }
 
 impl Intersections {
@@ -85,10 +87,38 @@ impl Intersections {
             &Self::One(_) => 1,
             &Self::Two(_, _) => 2,
             &Self::Many(ref v) => v.len(),
+            &Self::Infinite => return Count::Inf,
         }
         .into()
     }
 
+    pub fn len(&self) -> usize {
+        // number of listed points, so zero for Infinite
+        match self {
+            Self::Zero => 0,
+            Self::One(_) => 1,
+            Self::Two(_, _) => 2,
+            Self::Many(v) => v.len(),
+            Self::Infinite => 0,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        // no listed points, matching len, so true for Infinite as well
+        // use is_zero to ask whether there is no intersection at all
+        self.len() == 0
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Point> {
+        let (small, many): ([Option<&Point>; 2], &[Point]) = match self {
+            Self::Zero | Self::Infinite => ([None, None], &[]),
+            Self::One(a) => ([Some(a), None], &[]),
+            Self::Two(a, b) => ([Some(a), Some(b)], &[]),
+            Self::Many(v) => ([None, None], v),
+        };
+        small.into_iter().flatten().chain(many.iter())
+    }
+
     pub fn from_vec(points: Vec<Point>) -> Self {
         match points.len() {
             0 => Self::Zero,
@@ -111,26 +141,76 @@ impl Intersections {
     }
 
     pub fn into_vec(self) -> Vec<Point> {
+        // no points can be listed for Infinite, it gives an empty vec
         match self {
-            Self::Zero => Vec::new(),
+            Self::Zero | Self::Infinite => Vec::new(),
             Self::One(a) => vec![a],
             Self::Two(a, b) => vec![a, b],
             Self::Many(v) => v,
         }
     }
 
+    pub fn sorted_along(self, origin: Point, dir: Vector) -> Vec<Point> {
+        // points ordered by their projection onto dir, measured from origin
+        let mut res = self.into_vec();
+        res.sort_by(|p, q| {
+            let p_proj = (*p - origin).dot(dir);
+            let q_proj = (*q - origin).dot(dir);
+            p_proj.partial_cmp(&q_proj).unwrap()
+        });
+        res
+    }
+
+    pub fn sort_along(self, ray: &Ray) -> Self {
+        // ordered by distance along the ray, points behind its origin first
+        if self == Self::Infinite {
+            return self;
+        }
+        Self::from_vec(self.sorted_along(ray.init, ray.dir()))
+    }
+
+    pub fn first_hit(&self, ray: &Ray) -> Option<Point> {
+        // the nearest point in front of the ray's origin
+        self.iter()
+            .copied()
+            .filter(|&p| ray.bounds_contain(p))
+            .min_by(|p, q| {
+                let p_proj = (*p - ray.init).dot(ray.dir());
+                let q_proj = (*q - ray.init).dot(ray.dir());
+                p_proj.partial_cmp(&q_proj).unwrap()
+            })
+    }
+
+    pub fn dedup(self, tol: f64) -> Self {
+        // merge points within tol of an earlier point, e.g. the twin hits of a near tangency
+        if self == Self::Infinite {
+            return self;
+        }
+
+        let mut res: Vec<Point> = Vec::new();
+        for p in self {
+            if res.iter().all(|q| q.dist(p) > tol) {
+                res.push(p);
+            }
+        }
+        Self::from_vec(res)
+    }
+
     pub fn get_one(&self) -> Option<Point> {
         match self {
             &Self::Zero => None,
             &Self::One(a) => Some(a),
             &Self::Two(a, _) => Some(a),
             &Self::Many(ref v) => Some(v[0]),
+            &Self::Infinite => None,
         }
     }
 
     pub fn filter<T: FnMut(&Point) -> bool>(self, mut predicate: T) -> Self {
+        // Infinite can't be tested point by point, and is kept as is
         match self {
             Self::Zero => Self::Zero,
+            Self::Infinite => Self::Infinite,
             Self::One(a) => {
                 if predicate(&a) {
                     Self::One(a)
@@ -161,6 +241,10 @@ impl Intersections {
     }
 
     pub fn combine(self, other: Intersections) -> Self {
+        if self == Self::Infinite || other == Self::Infinite {
+            return Self::Infinite;
+        }
+
         if self == Self::Zero {
             return other;
         }
@@ -210,6 +294,38 @@ impl Intersections {
     }
 }
 
+// owning iterator over intersection points, allocation free for the small variants
+pub struct IntoIter {
+    small: std::iter::Flatten<std::array::IntoIter<Option<Point>, 2>>,
+    many: std::vec::IntoIter<Point>,
+}
+
+impl Iterator for IntoIter {
+    type Item = Point;
+
+    fn next(&mut self) -> Option<Point> {
+        self.small.next().or_else(|| self.many.next())
+    }
+}
+
+impl IntoIterator for Intersections {
+    type Item = Point;
+    type IntoIter = IntoIter;
+
+    fn into_iter(self) -> IntoIter {
+        let (small, many) = match self {
+            Self::Zero | Self::Infinite => ([None, None], Vec::new()),
+            Self::One(a) => ([Some(a), None], Vec::new()),
+            Self::Two(a, b) => ([Some(a), Some(b)], Vec::new()),
+            Self::Many(v) => ([None, None], v),
+        };
+        IntoIter {
+            small: small.into_iter().flatten(),
+            many: many.into_iter(),
+        }
+    }
+}
+
 pub trait Intersect<T> {
     fn intersects_at(&self, _other: &T) -> Intersections {
         unimplemented!()
@@ -236,30 +352,25 @@ pub(crate) use reflexive_intersect;
 // Line intersection definitions
 
 impl Intersect<Line> for Line {
-    fn intersects(&self, other: &Line) -> Count {
-        let disc = (self.a * other.b) - (self.b * other.a);
-
-        // future: some kind of tolerance?
-        if disc == 0.0 {
-            let scale = self.a / other.a;
-            if (self.b / other.b == scale) && (self.c / other.c == scale) {
-                Count::Inf
-            } else {
-                Count::Zero
-            }
-        } else {
-            Count::One
-        }
-    }
-
     fn intersects_at(&self, other: &Line) -> Intersections {
-        let denom = (self.b * other.a) - (self.a * other.b);
+        // cramer's rule on the columns of the system, zero determinant when parallel
+        let (a, b, c) = (
+            Point::new(self.a, other.a),
+            Point::new(self.b, other.b),
+            Point::new(self.c, other.c),
+        );
+        let det = a.cross(b);
 
         // maybe some tolerance?
-        if denom != 0.0 {
-            let x = ((self.b * other.c) - (self.c * other.b)) / denom;
-            let y = ((self.c * other.a) - (self.a * other.c)) / denom;
-            Intersections::One(Point::new(x, y))
+        if det != 0.0 {
+            return Intersections::One(Point::new(c.cross(b), a.cross(c)) / det);
+        }
+
+        // parallel, check whether they are the same line
+        let (x, y) = (self.normalized(), other.normalized());
+        let tol = 1e-9;
+        if (x.a - y.a).abs() < tol && (x.b - y.b).abs() < tol && (x.c - y.c).abs() < tol {
+            Intersections::Infinite
         } else {
             Intersections::Zero
         }
@@ -270,9 +381,11 @@ impl Intersect<Line> for Line {
 
 impl<T: Intersect<Line>> Intersect<T> for Segment {
     fn intersects_at(&self, other: &T) -> Intersections {
-        other
-            .intersects_at(&self.to_line())
-            .filter(|p| self.bounds_contain(*p))
+        match other.intersects_at(&self.to_line()) {
+            // colinear overlaps are not reported, see overlap_length
+            Intersections::Infinite => Intersections::Zero,
+            x => x.filter(|p| self.bounds_contain(*p)),
+        }
     }
 }
 
@@ -284,9 +397,12 @@ reflexive_intersect!(Circle, Segment);
 
 impl<T: Intersect<Line>> Intersect<T> for Ray {
     fn intersects_at(&self, other: &T) -> Intersections {
-        other
-            .intersects_at(&self.to_line())
-            .filter(|p| self.bounds_contain(*p))
+        // hits are ordered near to far, so get_one gives the first hit
+        match other.intersects_at(&self.to_line()) {
+            // as for segments, colinear overlaps are not reported
+            Intersections::Infinite => Intersections::Zero,
+            x => x.filter(|p| self.bounds_contain(*p)).sort_along(self),
+        }
     }
 }
 
@@ -304,12 +420,36 @@ impl Intersect<Circle> for Circle {
 
         let dist = c1.dist(c2);
 
-        if dist > (r1 + r2) {
+        // TODO replace with some tolerance interface
+        let tol = 1e-9;
+
+        if dist <= tol {
+            // concentric, either one inside the other or the same circle
+            if (r1 - r2).abs() <= tol {
+                return Intersections::Infinite;
+            }
+            return Intersections::Zero;
+        }
+
+        if dist > (r1 + r2) + tol {
+            // too far apart
+            return Intersections::Zero;
+        }
+
+        if dist < (r1 - r2).abs() - tol {
+            // one entirely inside the other
             return Intersections::Zero;
         }
 
-        if dist == (r1 + r2) {
-            return Intersections::from_one(((c1 * r2) + (c2 * r1)) / dist);
+        if (dist - (r1 + r2)).abs() <= tol {
+            // externally tangent
+            return Intersections::from_one(((c1 * r2) + (c2 * r1)) / (r1 + r2));
+        }
+
+        if (dist - (r1 - r2).abs()).abs() <= tol {
+            // internally tangent, touching on the far side of the smaller circle
+            let (big, small, r) = if r1 >= r2 { (c1, c2, r1) } else { (c2, c1, r2) };
+            return Intersections::from_one(big + ((small - big) / dist) * r);
         }
 
         let mid = c1.mid(c2);
@@ -319,7 +459,7 @@ impl Intersect<Circle> for Circle {
         let inner = (2.0 * (r1.powi(2) + r2.powi(2)) / dist.powi(2)) - (a * 2.0).powi(2);
         let b = (inner - 1.0).sqrt() / 2.0;
 
-        let b_dir = Point::new(c2.y - c1.y, c1.x - c2.x);
+        let b_dir = Vector::new(c2.y - c1.y, c1.x - c2.x);
 
         let p1 = (mid + ((c2 - c1) * a)) + (b_dir * b);
         let p2 = (mid + ((c2 - c1) * a)) - (b_dir * b);
@@ -351,11 +491,11 @@ impl Intersect<Line> for Circle {
         // half of the chord of the circle
         let half_chord = (r.powi(2) - dist.powi(2)).sqrt();
 
-        let unit_radial = inter.to_unit();
-        let unit_tangential = unit_radial.perp();
+        // step along the line itself, which stays defined when it passes through the center
+        let unit_tangential = Vector::new(shifted_line.b, -shifted_line.a).normalize();
 
-        let p1 = (unit_radial * dist) + (unit_tangential * half_chord);
-        let p2 = (unit_radial * dist) - (unit_tangential * half_chord);
+        let p1 = inter + (unit_tangential * half_chord);
+        let p2 = inter - (unit_tangential * half_chord);
 
         // shift back to original coordinate frame
         Intersections::Two(p1 + self.center, p2 + self.center)
@@ -364,13 +504,146 @@ impl Intersect<Line> for Circle {
 
 reflexive_intersect!(Line, Circle);
 
+// Ellipse intersection definitions
+
+impl Intersect<Line> for Ellipse {
+    fn intersects_at(&self, other: &Line) -> Intersections {
+        // in the ellipse's frame, scaled so the ellipse is the unit circle, a point stepping
+        // along the line at unit speed in the original frame gives a quadratic in the step
+        let (a, b) = (self.semi_major, self.semi_minor);
+        let scaled = |p: Point| Point::new(p.x / a, p.y / b);
+
+        let start = other.projected(self.center);
+        let dir = Vector::new(other.b, -other.a).normalize();
+        // both taken from the ellipse's center, the origin of its frame
+        let p0 = scaled(self.frame_coords(start)) - Point::ZERO;
+        let d = scaled(self.frame_coords(start + dir)) - scaled(self.frame_coords(start));
+
+        let (qa, qb, qc) = (d.dot(d), 2.0 * p0.dot(d), p0.dot(p0) - 1.0);
+        let disc = qb * qb - 4.0 * qa * qc;
+        if disc < 0.0 {
+            Intersections::Zero
+        } else if disc == 0.0 {
+            Intersections::from_one(start + dir * (-qb / (2.0 * qa)))
+        } else {
+            let root = disc.sqrt();
+            Intersections::Two(
+                start + dir * ((-qb + root) / (2.0 * qa)),
+                start + dir * ((-qb - root) / (2.0 * qa)),
+            )
+        }
+    }
+}
+
+reflexive_intersect!(Line, Ellipse);
+reflexive_intersect!(Ellipse, Segment);
+reflexive_intersect!(Ellipse, Ray);
+
+// Bezier intersection definitions
+
+fn cubic_roots_in_unit(coeffs: [f64; 4]) -> Vec<f64> {
+    // roots in [0, 1] of c0 + c1 t + c2 t^2 + c3 t^3
+    // the interval is cut at the turning points, so the cubic is monotone on each piece and
+    // a sign change there brackets exactly one root, found by bisection
+    // double roots that only touch zero are missed, as for tangent circles
+    let [c0, c1, c2, c3] = coeffs;
+    let f = |t: f64| ((c3 * t + c2) * t + c1) * t + c0;
+
+    // turning points, from 3 c3 t^2 + 2 c2 t + c1 = 0
+    let mut cuts = vec![0.0];
+    let (a, b, c) = (3.0 * c3, 2.0 * c2, c1);
+    if a != 0.0 {
+        let disc = b * b - 4.0 * a * c;
+        if disc >= 0.0 {
+            let root = disc.sqrt();
+            cuts.push((-b - root) / (2.0 * a));
+            cuts.push((-b + root) / (2.0 * a));
+        }
+    } else if b != 0.0 {
+        cuts.push(-c / b);
+    }
+    cuts.retain(|&t| (0.0..1.0).contains(&t));
+    cuts.sort_by(|x, y| x.partial_cmp(y).unwrap());
+    cuts.push(1.0);
+
+    let mut res = Vec::new();
+    for w in cuts.windows(2) {
+        let (mut lo, mut hi) = (w[0], w[1]);
+        let (flo, fhi) = (f(lo), f(hi));
+        if flo == 0.0 {
+            res.push(lo);
+            continue;
+        }
+        if fhi == 0.0 || (flo < 0.0) == (fhi < 0.0) {
+            continue;
+        }
+
+        for _ in 0..64 {
+            let mid = (lo + hi) / 2.0;
+            if mid <= lo || mid >= hi {
+                break;
+            }
+            if (f(mid) < 0.0) == (flo < 0.0) {
+                lo = mid;
+            } else {
+                hi = mid;
+            }
+        }
+        res.push((lo + hi) / 2.0);
+    }
+    if f(1.0) == 0.0 {
+        res.push(1.0);
+    }
+    res
+}
+
+impl Intersect<Line> for CubicBezier {
+    fn intersects_at(&self, other: &Line) -> Intersections {
+        // the signed distance to the line along the curve is itself a cubic, with the
+        // distances of the control points as its bernstein coefficients
+        let [d0, d1, d2, d3] = self
+            .controls()
+            .map(|p| other.a * p.x + other.b * p.y - other.c);
+        if [d0, d1, d2, d3].iter().all(|&d| d == 0.0) {
+            // the curve is straight, along the line
+            return Intersections::Infinite;
+        }
+
+        // to the power basis
+        let coeffs = [
+            d0,
+            3.0 * (d1 - d0),
+            3.0 * (d0 - 2.0 * d1 + d2),
+            d3 - d0 + 3.0 * (d1 - d2),
+        ];
+        let hits = cubic_roots_in_unit(coeffs)
+            .into_iter()
+            .map(|t| self.point_at(t))
+            .collect();
+
+        // TODO replace with some tolerance interface
+        Intersections::from_vec(hits).dedup(1e-9)
+    }
+}
+
+reflexive_intersect!(Line, CubicBezier);
+reflexive_intersect!(CubicBezier, Segment);
+reflexive_intersect!(CubicBezier, Ray);
+
 // Arc intersection definitions
 
+// arcs against arcs and bounded lines come through here too, with the other shape's own
+// bounds applied by its intersection with the circle
 impl<T: Intersect<Circle>> Intersect<T> for Arc {
     fn intersects_at(&self, other: &T) -> Intersections {
-        other
-            .intersects_at(&self.to_circle())
-            .filter(|p| self.bounds_contain(*p))
+        // TODO replace with some tolerance interface
+        let tol = 1e-9;
+
+        match other.intersects_at(&self.to_circle()) {
+            // overlaps along a shared circle are not reported
+            Intersections::Infinite => Intersections::Zero,
+            x => x.filter(|p| self.bounds_contain_tol(*p, tol)).dedup(tol),
+        }
     }
 }
 
@@ -381,6 +654,75 @@ reflexive_intersect!(Circle, Arc);
 mod tests {
     use super::*;
 
+    #[test]
+    fn line_ellipse() {
+        let e = Ellipse::new((1.0, 1.0).into(), 2.0, 1.0, 0.0);
+
+        // the horizontal line through the center cuts at the ends of the major axis
+        let mut hits = Line::new(0.0, 1.0, 1.0).intersects_at(&e).into_vec();
+        hits.sort_by(|a, b| a.x.partial_cmp(&b.x).unwrap());
+        assert_eq!(hits.len(), 2);
+        assert!(hits[0].eps_eq((-1.0, 1.0).into(), 1e-12));
+        assert!(hits[1].eps_eq((3.0, 1.0).into(), 1e-12));
+
+        // a slanted line through a turned ellipse, hits land on the curve
+        let turned = Ellipse::new((0.0, 2.0).into(), 3.0, 1.5, 0.7);
+        let line = Line::from_points((-1.0, 0.0).into(), (1.0, 3.0).into());
+        let hits = turned.intersects_at(&line).into_vec();
+        assert_eq!(hits.len(), 2);
+        for p in hits {
+            assert!(turned.dist(p) < 1e-9);
+            assert!(line.dist(p) < 1e-9);
+        }
+
+        assert_eq!(
+            Line::new(0.0, 1.0, 5.0).intersects_at(&e).count(),
+            Count::Zero
+        );
+        let seg = Segment::new((1.0, 1.0).into(), (5.0, 1.0).into());
+        assert_eq!(e.intersects_at(&seg).len(), 1);
+    }
+
+    #[test]
+    fn bezier_line() {
+        let curve = CubicBezier(
+            (0.0, 0.0).into(),
+            (1.0, 2.0).into(),
+            (2.0, -2.0).into(),
+            (3.0, 0.0).into(),
+        );
+
+        // the x axis passes through both ends and the middle
+        let mut hits = Line::new(0.0, 1.0, 0.0).intersects_at(&curve).into_vec();
+        hits.sort_by(|a, b| a.x.partial_cmp(&b.x).unwrap());
+        assert_eq!(hits.len(), 3);
+        assert!(hits[0].eps_eq((0.0, 0.0).into(), 1e-12));
+        assert!(hits[1].eps_eq((1.5, 0.0).into(), 1e-12));
+        assert!(hits[2].eps_eq((3.0, 0.0).into(), 1e-12));
+
+        // a segment reaching only the first lobe
+        let seg = Segment::new((0.5, -1.0).into(), (0.5, 1.0).into());
+        let hit = seg.intersects_at(&curve).get_one().unwrap();
+        assert!(curve.dist(hit) < 1e-9);
+        assert!((hit.x - 0.5).abs() < 1e-12);
+
+        assert_eq!(
+            Line::new(0.0, 1.0, 5.0).intersects_at(&curve).count(),
+            Count::Zero
+        );
+
+        let straight = CubicBezier(
+            (0.0, 0.0).into(),
+            (1.0, 0.0).into(),
+            (2.0, 0.0).into(),
+            (3.0, 0.0).into(),
+        );
+        assert_eq!(
+            straight.intersects_at(&Line::new(0.0, 1.0, 0.0)),
+            Intersections::Infinite
+        );
+    }
+
     #[test]
     fn line_line_one() {
         let a = Line::new(2.0, 3.0, 2.0);
@@ -402,6 +744,22 @@ mod tests {
         assert_eq!(a.intersects(&b), a.intersects_at(&b).count());
     }
 
+    #[test]
+    fn line_line_inf() {
+        let a = Line::new(2.0, 3.0, 2.0);
+        let b = Line::new(-4.0, -6.0, -4.0);
+        assert_eq!(a.intersects(&b), Count::Inf);
+
+        // horizontal and vertical lines have a zero coefficient
+        let a = Line::new(0.0, 2.0, 2.0);
+        let b = Line::new(0.0, -1.0, -1.0);
+        assert_eq!(a.intersects(&b), Count::Inf);
+
+        let a = Line::new(3.0, 0.0, 3.0);
+        let b = Line::new(1.0, 0.0, 2.0);
+        assert_eq!(a.intersects(&b), Count::Zero);
+    }
+
     #[test]
     fn line_segment_one() {
         let a = Line::new(2.0, 3.0, 2.0);
@@ -560,4 +918,218 @@ mod tests {
 
         assert!(case_a || case_b);
     }
+
+    #[test]
+    fn sorted_along() {
+        let x = Intersections::from_vec(vec![
+            (3.0, 1.0).into(),
+            (-1.0, 5.0).into(),
+            (0.5, 0.0).into(),
+            (2.0, 2.0).into(),
+        ]);
+        assert_eq!(x.count(), Count::Many(4));
+
+        let sorted = x.sorted_along((1.0, 1.0).into(), (1.0, 0.0).into());
+        let xs: Vec<f64> = sorted.iter().map(|p| p.x).collect();
+        assert_eq!(xs, vec![-1.0, 0.5, 2.0, 3.0]);
+    }
+
+    #[test]
+    fn iterate() {
+        let two = Intersections::from_two((1.0, 2.0).into(), (3.0, 4.0).into());
+        assert_eq!(two.len(), 2);
+        assert_eq!(two.iter().count(), 2);
+        assert_eq!(two.iter().map(|p| p.x).sum::<f64>(), 4.0);
+
+        let many = Intersections::from_vec(vec![
+            (0.0, 0.0).into(),
+            (1.0, 0.0).into(),
+            (2.0, 0.0).into(),
+        ]);
+        assert_eq!(many.len(), 3);
+
+        let mut count = 0;
+        for p in many {
+            assert_eq!(p.x, count as f64);
+            count += 1;
+        }
+        assert_eq!(count, 3);
+
+        assert_eq!(Intersections::Zero.into_iter().count(), 0);
+        assert!(Intersections::Zero.is_empty());
+
+        // Infinite lists no points, but is not Zero
+        assert_eq!(Intersections::Infinite.len(), 0);
+        assert!(Intersections::Infinite.is_empty());
+        assert!(!Intersections::Infinite.is_zero());
+        assert!(!Intersections::One((0.0, 0.0).into()).is_empty());
+    }
+
+    #[test]
+    fn first_hit() {
+        let circle = Circle::new((5.0, 1.0).into(), 2.0);
+        let ray = Ray::new((0.0, 1.0).into(), 0.0);
+
+        let hits = ray.to_line().intersects_at(&circle);
+        let sorted = hits.clone().sort_along(&ray);
+        let xs: Vec<f64> = sorted.iter().map(|p| p.x).collect();
+        assert!((xs[0] - 3.0).abs() < 1e-9 && (xs[1] - 7.0).abs() < 1e-9);
+
+        let hit = hits.first_hit(&ray).unwrap();
+        assert!(hit.dist((3.0, 1.0).into()) < 1e-9);
+
+        // from inside the circle, the near side is behind the ray
+        let inside = Ray::new((6.0, 1.0).into(), 0.0);
+        let hit = hits.first_hit(&inside).unwrap();
+        assert!(hit.dist((7.0, 1.0).into()) < 1e-9);
+
+        let away = Ray::new((0.0, 1.0).into(), std::f64::consts::PI);
+        assert_eq!(hits.first_hit(&away), None);
+    }
+
+    #[test]
+    fn circle_circle_tangent() {
+        let a = Circle::new((0.0, 0.0).into(), 2.0);
+
+        // externally tangent
+        let b = Circle::new((3.0, 0.0).into(), 1.0);
+        let x = a.intersects_at(&b);
+        assert_eq!(x.count(), Count::One);
+        assert!(x.get_one().unwrap().dist((2.0, 0.0).into()) < 1e-9);
+
+        // internally tangent, either way round
+        let c = Circle::new((0.0, 1.0).into(), 1.0);
+        for x in [a.intersects_at(&c), c.intersects_at(&a)] {
+            assert_eq!(x.count(), Count::One);
+            assert!(x.get_one().unwrap().dist((0.0, 2.0).into()) < 1e-9);
+        }
+
+        // nearly tangent within tolerance
+        let d = Circle::new((3.0 + 1e-12, 0.0).into(), 1.0);
+        assert_eq!(a.intersects(&d), Count::One);
+
+        // concentric
+        let e = Circle::new((0.0, 0.0).into(), 1.0);
+        assert_eq!(a.intersects(&e), Count::Zero);
+        assert_eq!(a.intersects(&a), Count::Inf);
+    }
+
+    #[test]
+    fn circle_arc_tangent() {
+        use std::f64::consts::PI;
+
+        let circle = Circle::new((3.0, 0.0).into(), 1.0);
+
+        // the tangent point lies on the right half of the arc's circle
+        let right = Arc::from_center_ang((0.0, 0.0).into(), 2.0, 1.5 * PI, 0.5 * PI, true);
+        let x = right.intersects_at(&circle);
+        assert_eq!(x.count(), Count::One);
+        assert!(x.get_one().unwrap().dist((2.0, 0.0).into()) < 1e-9);
+
+        let left = Arc::from_center_ang((0.0, 0.0).into(), 2.0, 0.5 * PI, 1.5 * PI, true);
+        assert_eq!(left.intersects(&circle), Count::Zero);
+    }
+
+    #[test]
+    fn circle_circle_contained() {
+        let a = Circle::new((0.0, 0.0).into(), 3.0);
+        let b = Circle::new((1.0, 0.5).into(), 1.0);
+        assert_eq!(a.intersects_at(&b), Intersections::Zero);
+        assert_eq!(b.intersects_at(&a), Intersections::Zero);
+
+        // coincident
+        let c = Circle::new((0.0, 0.0).into(), 3.0);
+        assert_eq!(a.intersects_at(&c), Intersections::Infinite);
+
+        // overlapping still gives two finite points
+        let d = Circle::new((3.0, 0.0).into(), 1.0);
+        let x = a.intersects_at(&d).into_vec();
+        assert_eq!(x.len(), 2);
+        assert!(x.iter().all(|p| p.x.is_finite() && p.y.is_finite()));
+    }
+
+    #[test]
+    fn coincident_infinite() {
+        let a = Line::new(2.0, 3.0, 2.0);
+        let b = Line::new(-4.0, -6.0, -4.0);
+        assert_eq!(a.intersects_at(&b), Intersections::Infinite);
+        assert_eq!(a.intersects(&b), Count::Inf);
+        assert_eq!(a.intersects(&b), a.intersects_at(&b).count());
+
+        let parallel = Line::new(2.0, 3.0, 5.0);
+        assert_eq!(a.intersects_at(&parallel), Intersections::Zero);
+        assert_eq!(a.intersects(&parallel), Count::Zero);
+
+        let circle = Circle::new((1.0, 1.0).into(), 2.0);
+        assert_eq!(circle.intersects(&circle), Count::Inf);
+
+        // infinite absorbs anything combined with it, and has no listable points
+        let x = Intersections::Infinite.combine(Intersections::One((0.0, 0.0).into()));
+        assert_eq!(x, Intersections::Infinite);
+        assert_eq!(x.clone().filter(|_| false), Intersections::Infinite);
+        assert!(x.get_one().is_none());
+        assert!(x.into_vec().is_empty());
+    }
+
+    #[test]
+    fn arc_arc_swept_range() {
+        use std::f64::consts::PI;
+
+        // the full circles meet at (1, +-sqrt(3))
+        let a = Arc::from_center_ang((0.0, 0.0).into(), 2.0, 0.0, PI, true);
+        let b = Arc::from_center_ang((2.0, 0.0).into(), 2.0, 0.0, PI, true);
+        assert_eq!(a.to_circle().intersects(&b.to_circle()), Count::Many(2));
+
+        // both upper halves, so only the upper point
+        let x = a.intersects_at(&b);
+        assert_eq!(x.count(), Count::One);
+        assert!(x.get_one().unwrap().dist((1.0, 3.0f64.sqrt()).into()) < 1e-9);
+        assert_eq!(b.intersects(&a), Count::One);
+
+        // an upper half against a lower half share neither point
+        let c = Arc::from_center_ang((2.0, 0.0).into(), 2.0, PI, 0.0, true);
+        assert_eq!(a.intersects(&c), Count::Zero);
+        assert_eq!(c.intersects(&a), Count::Zero);
+
+        // a clockwise arc still includes its own ends
+        let d = Arc::from_center_ang((2.0, 0.0).into(), 2.0, 2.0 * PI / 3.0, PI / 3.0, false);
+        assert_eq!(a.intersects(&d), Count::One);
+    }
+
+    #[test]
+    fn arc_segment_ends() {
+        use std::f64::consts::PI;
+
+        // a segment ending exactly on the end of an arc, and one tangent to it
+        let a = Arc::from_center_ang((0.0, 0.0).into(), 1.0, 0.0, PI / 2.0, false);
+        let s = Segment::new((1.0, 0.0).into(), (3.0, 0.0).into());
+        assert_eq!(a.intersects(&s), Count::One);
+        assert_eq!(s.intersects(&a), Count::One);
+
+        let tangent = Segment::new((1.0, -1.0).into(), (1.0, 1.0).into());
+        assert_eq!(a.intersects(&tangent), Count::One);
+    }
+
+    #[test]
+    fn ray_arc_order() {
+        use std::f64::consts::PI;
+
+        // rays along the x axis from either side, entering and leaving through the arc
+        let arc = Arc::from_center_ang((0.0, 0.0).into(), 1.0, PI / 2.0, 2.0 * PI, true);
+        let circle = arc.to_circle();
+        for ang in [0.0, PI] {
+            let ray = Ray::new(Point::unit(ang + PI) * 3.0, ang);
+            let entry = Point::unit(ang + PI);
+
+            for x in [
+                ray.intersects_at(&arc),
+                arc.intersects_at(&ray),
+                ray.intersects_at(&circle),
+                circle.intersects_at(&ray),
+            ] {
+                assert_eq!(x.count(), Count::Many(2));
+                assert!(x.get_one().unwrap().dist(entry) < 1e-9);
+            }
+        }
+    }
 }
@@ -1,7 +1,12 @@
 use std::fmt;
 
+use super::aabb::Aabb;
+use super::dist::Dist;
+use super::error::SpaceMathError;
 use super::intersect::Intersect;
-use super::Point;
+use super::triangle::orient;
+use super::{Point, Vector};
+use crate::Orient;
 
 // a line, represented as ax + by = c
 #[derive(Clone, Copy, Debug)]
@@ -16,6 +21,125 @@ impl Line {
         Self { a, b, c }
     }
 
+    pub fn from_slope_intercept(m: f64, b: f64) -> Self {
+        // y = mx + b => -mx + y = b
+        Self {
+            a: -m,
+            b: 1.0,
+            c: b,
+        }
+    }
+
+    pub fn from_point_slope(p: Point, m: f64) -> Self {
+        // y - p.y = m(x - p.x), with an infinite slope giving the vertical line x = p.x
+        if m.is_infinite() {
+            Self {
+                a: 1.0,
+                b: 0.0,
+                c: p.x,
+            }
+        } else {
+            Self {
+                a: -m,
+                b: 1.0,
+                c: p.y - m * p.x,
+            }
+        }
+    }
+
+    pub fn from_points(p: Point, q: Point) -> Self {
+        // the line through p and q, valid for any direction including vertical
+        let a = q.y - p.y;
+        let b = p.x - q.x;
+        let c = a * p.x + b * p.y;
+        Self { a, b, c }
+    }
+
+    pub fn fit(points: &[Point]) -> Option<Line> {
+        // total least squares fit, minimizing the perpendicular distances to the line
+        // the line runs through the centroid along the principal axis of the covariance
+        // none for fewer than two distinct points
+        if points.len() < 2 {
+            return None;
+        }
+
+        let n = points.len() as f64;
+        let mean = points.iter().fold(Point::origin(), |acc, p| acc + *p) / n;
+        let (mut sxx, mut syy, mut sxy) = (0.0, 0.0, 0.0);
+        for p in points {
+            let d = *p - mean;
+            sxx += d.x * d.x;
+            syy += d.y * d.y;
+            sxy += d.x * d.y;
+        }
+        if sxx + syy == 0.0 {
+            return None;
+        }
+
+        // angle of the eigenvector with the largest eigenvalue
+        let ang = 0.5 * (2.0 * sxy).atan2(sxx - syy);
+        let normal = Point::unit(ang).perp();
+        Some(Line::new(normal.x, normal.y, normal.dot(mean)).normalized())
+    }
+
+    pub fn normalized(self) -> Line {
+        // scale so that a^2 + b^2 = 1, with a positive (or b positive for horizontal lines)
+        // every coefficient triple describing the same line normalizes to the same triple
+        // the sign is chosen after scaling, so the threshold doesn't depend on the input's scale
+        let norm = (self.a.powi(2) + self.b.powi(2)).sqrt();
+        let (a, b, c) = (self.a / norm, self.b / norm, self.c / norm);
+        let sign = if a.abs() > 1e-12 {
+            a.signum()
+        } else {
+            b.signum()
+        };
+        Line {
+            a: a * sign,
+            b: b * sign,
+            c: c * sign,
+        }
+    }
+
+    pub fn slope(self) -> Option<f64> {
+        // dy/dx, none for vertical lines
+        if self.b == 0.0 {
+            None
+        } else {
+            Some(-self.a / self.b)
+        }
+    }
+
+    pub fn angle(self) -> f64 {
+        // angle of the line's direction from the x axis, in [0, pi)
+        let ang = (-self.a).atan2(self.b).rem_euclid(std::f64::consts::PI);
+        if ang >= std::f64::consts::PI {
+            0.0
+        } else {
+            ang
+        }
+    }
+
+    pub fn is_parallel(&self, other: &Line, tol: f64) -> bool {
+        // normals cross to (nearly) zero, tol bounding the sine of the angle between
+        // coincident lines count as parallel
+        self.normal().shoelace(other.normal()).abs() <= tol
+    }
+
+    pub fn is_perpendicular(&self, other: &Line, tol: f64) -> bool {
+        // normals dot to (nearly) zero, tol bounding the cosine of the angle between
+        self.normal().dot(other.normal()).abs() <= tol
+    }
+
+    pub fn angle_between(&self, other: &Line) -> f64 {
+        // the acute angle between the lines, in [0, pi/2], as lines have no direction
+        let (n, m) = (self.normal(), other.normal());
+        n.shoelace(m).abs().atan2(n.dot(m).abs())
+    }
+
+    fn normal(&self) -> Point {
+        Point::new(self.a, self.b).to_unit()
+    }
+
     pub fn perp_origin(&self) -> Line {
         // a perpendicular line passing through the origin
         let c = 0.0;
@@ -42,6 +166,12 @@ impl Line {
         self.intersects_at(&perp).get_one().unwrap()
     }
 
+    pub fn reflect(&self, p: Point) -> Point {
+        // mirror image of p across the line
+        let foot = self.projected(p);
+        foot + (foot - p)
+    }
+
     // public to crate only - use the Shift trait
     pub(crate) fn offset_x(&mut self, u: f64) {
         // shift the line by some x value
@@ -88,16 +218,18 @@ impl Segment {
     }
 
     pub fn to_line(self) -> Line {
-        let a = self.q.y - self.p.y;
-        let b = self.p.x - self.q.x;
-        let c = a * self.p.x + b * self.p.y;
-        Line::new(a, b, c)
+        Line::from_points(self.p, self.q)
     }
 
     pub fn mid(&self) -> Point {
         self.p.mid(self.q)
     }
 
+    pub fn dir(&self) -> Vector {
+        // unit vector pointing from p to q
+        (self.q - self.p).normalize()
+    }
+
     pub fn perp_bisect(s: Segment) -> Line {
         // contruct a perpendicular bisector of pq
         let mid = s.mid();
@@ -128,6 +260,69 @@ impl Segment {
         a && b && c && d
     }
 
+    pub fn clamp(self, r: Point) -> Point {
+        // closest point on the segment to r
+        // projects r onto the segment's line, clamping to the ends
+        let d = self.q - self.p;
+        let len2 = d.dot(d);
+        if len2 == 0.0 {
+            return self.p;
+        }
+
+        let t = ((r - self.p).dot(d) / len2).clamp(0.0, 1.0);
+        self.p + d * t
+    }
+
+    pub fn split_at(self, r: Point) -> (Segment, Segment) {
+        // the pieces from p to r and from r to q, r assumed to lie on the segment
+        // r is used as is, so the pieces meet exactly
+        (Segment::new(self.p, r), Segment::new(r, self.q))
+    }
+
+    pub fn split_at_t(self, t: f64) -> (Segment, Segment) {
+        // split at the point a fraction t of the way from p to q
+        self.split_at(self.p.lerp(self.q, t))
+    }
+
+    pub fn offset(self, dist: f64) -> Segment {
+        // parallel segment dist to the right of the direction of travel (left if negative)
+        // for a counterclockwise boundary, positive offsets move outward
+        let shift = -(self.q - self.p).perp().normalize() * dist;
+        Segment {
+            p: self.p + shift,
+            q: self.q + shift,
+        }
+    }
+
+    pub fn overlap_length(&self, other: &Segment, tol: f64) -> f64 {
+        // length shared by two colinear segments, zero if they are not colinear
+        let len = self.p.dist(self.q);
+        if len <= tol {
+            return 0.0;
+        }
+
+        // other's ends must lie on this segment's line
+        let dir = (self.q - self.p) / len;
+        let off_line = |r: Point| (r - self.p).cross(dir).abs();
+        if off_line(other.p) > tol || off_line(other.q) > tol {
+            return 0.0;
+        }
+
+        // compare positions along the shared line
+        let t0 = (other.p - self.p).dot(dir);
+        let t1 = (other.q - self.p).dot(dir);
+        (len.min(t0.max(t1)) - t0.min(t1).max(0.0)).max(0.0)
+    }
+
+    pub fn reflect(&self, line: &Line) -> Segment {
+        // mirror image of the segment across the line
+        // note that mirroring flips handedness, so a ccw boundary of reflected segments is cw
+        Segment {
+            p: line.reflect(self.p),
+            q: line.reflect(self.q),
+        }
+    }
+
     pub fn reverse(self) -> Self {
         Segment {
             p: self.q,
@@ -147,9 +342,60 @@ impl Ray {
         Ray { init, ang }
     }
 
-    pub fn dir(&self) -> Point {
+    pub fn from_points(init: Point, through: Point) -> Self {
+        // ray starting at init and passing through another point
+        Ray {
+            init,
+            ang: (through - init).ang(),
+        }
+    }
+
+    pub fn reflect(&self, line: &Line) -> Ray {
+        // the ray reflected off the line, starting at the point of incidence
+        // returns the ray unchanged if it never reaches the line
+        if self.parallel_to(line) {
+            return *self;
+        }
+
+        match self.intersects_at(line).get_one() {
+            Some(hit) => self.reflect_at(hit, line),
+            None => *self,
+        }
+    }
+
+    pub fn reflect_segment(&self, s: &Segment) -> Ray {
+        // as reflect, but only if the ray hits within the segment
+        if self.parallel_to(&s.to_line()) {
+            return *self;
+        }
+
+        match self.intersects_at(s).get_one() {
+            Some(hit) => self.reflect_at(hit, &s.to_line()),
+            None => *self,
+        }
+    }
+
+    fn parallel_to(&self, line: &Line) -> bool {
+        // direction has no component along the line's normal
+        // checked with a tolerance, as the exact line intersection finds a point at ~infinity
+        let n = Vector::new(line.a, line.b).normalize();
+        self.dir().dot(n).abs() < 1e-12
+    }
+
+    fn reflect_at(&self, hit: Point, line: &Line) -> Ray {
+        // mirror the direction about the line: d - 2(d.n)n
+        let n = Vector::new(line.a, line.b).normalize();
+        let d = self.dir();
+        let reflected = d - (n * (2.0 * d.dot(n)));
+        Ray {
+            init: hit,
+            ang: reflected.ang(),
+        }
+    }
+
+    pub fn dir(&self) -> Vector {
         // unit vector representing the pointing direction
-        Point::unit(self.ang)
+        Vector::unit(self.ang)
     }
 
     pub fn to_line(self) -> Line {
@@ -163,10 +409,16 @@ impl Ray {
     pub fn bounds_contain(&self, r: Point) -> bool {
         // check whether r lies 'in front' of the ray's origin
         let shifted = r - self.init;
-        let unit_forward = Point::unit(self.ang);
+        let unit_forward = Vector::unit(self.ang);
         shifted.dot(unit_forward) > 0.0
     }
 
+    pub fn clamp(self, r: Point) -> Point {
+        // closest point on the ray to r, the origin if r is behind it
+        let t = (r - self.init).dot(self.dir()).max(0.0);
+        self.init + self.dir() * t
+    }
+
     // public to crate only - use the Shift trait
     pub(crate) fn offset_x(&mut self, u: f64) {
         self.init.x += u;
@@ -197,9 +449,237 @@ impl Circle {
         self.center.y += v;
     }
 
+    pub fn min_enclosing(points: &[Point]) -> Option<Circle> {
+        // smallest circle containing every point, none if there are no points
+        // welzl's algorithm in its iterative form: expected linear time, given the points in
+        // random order, so they are first shuffled (deterministically)
+        let mut pts = points.to_vec();
+        let mut seed: u64 = 0x9e37_79b9_7f4a_7c15;
+        for i in (1..pts.len()).rev() {
+            seed = seed
+                .wrapping_mul(6_364_136_223_846_793_005)
+                .wrapping_add(1_442_695_040_888_963_407);
+            pts.swap(i, (seed >> 33) as usize % (i + 1));
+        }
+
+        // TODO replace with some tolerance interface
+        let covers = |c: &Circle, p: Point| c.center.dist(p) <= c.radius * (1.0 + 1e-12) + 1e-12;
+        let diameter = |p: Point, q: Point| Circle::new(p.mid(q), p.dist(q) / 2.0);
+        let through = |p: Point, q: Point, r: Point| {
+            if orient(p, q, r) == Orient::Zero {
+                // colinear, so the two farthest apart span the circle
+                [diameter(p, q), diameter(q, r), diameter(p, r)]
+                    .into_iter()
+                    .max_by(|a, b| a.radius.partial_cmp(&b.radius).unwrap())
+                    .unwrap()
+            } else {
+                let center = super::Triangle::from((p, q, r)).circumcenter();
+                Circle::new(center, center.dist(p))
+            }
+        };
+
+        let mut c = Circle::new(*pts.first()?, 0.0);
+        for i in 1..pts.len() {
+            if covers(&c, pts[i]) {
+                continue;
+            }
+            // pts[i] is on the boundary of the smallest circle around pts[..=i]
+            c = Circle::new(pts[i], 0.0);
+            for j in 0..i {
+                if covers(&c, pts[j]) {
+                    continue;
+                }
+                // and so is pts[j], for the circle around pts[..=j] and pts[i]
+                c = diameter(pts[i], pts[j]);
+                for k in 0..j {
+                    if !covers(&c, pts[k]) {
+                        c = through(pts[i], pts[j], pts[k]);
+                    }
+                }
+            }
+        }
+
+        Some(c)
+    }
+
+    pub fn fit(points: &[Point]) -> Option<Circle> {
+        // kasa's algebraic fit, minimizing the sum of (|p - center|^2 - radius^2)^2
+        // solved in coordinates relative to the centroid, where the normal equations reduce
+        // to a 2x2 system for the center
+        // none for fewer than three points, or points too close to a line
+        if points.len() < 3 {
+            return None;
+        }
+
+        let n = points.len() as f64;
+        let mean = points.iter().fold(Point::origin(), |acc, p| acc + *p) / n;
+
+        let (mut suu, mut svv, mut suv) = (0.0, 0.0, 0.0);
+        let (mut su3, mut sv3, mut suvv, mut svuu) = (0.0, 0.0, 0.0, 0.0);
+        for p in points {
+            let (u, v) = (p.x - mean.x, p.y - mean.y);
+            suu += u * u;
+            svv += v * v;
+            suv += u * v;
+            su3 += u * u * u;
+            sv3 += v * v * v;
+            suvv += u * v * v;
+            svuu += v * u * u;
+        }
+
+        // TODO replace with some tolerance interface
+        let det = suu * svv - suv * suv;
+        if det <= 1e-12 * (suu + svv).powi(2) {
+            return None;
+        }
+
+        let (bu, bv) = ((su3 + suvv) / 2.0, (sv3 + svuu) / 2.0);
+        let uc = (bu * svv - bv * suv) / det;
+        let vc = (bv * suu - bu * suv) / det;
+
+        let radius = (uc * uc + vc * vc + (suu + svv) / n).sqrt();
+        Some(Circle::new(mean + Point::new(uc, vc), radius))
+    }
+
     pub fn at_ang(&self, ang: f64) -> Point {
         (Point::unit(ang) * self.radius) + self.center
     }
+
+    pub fn point_in_direction(&self, dir: Vector) -> Point {
+        // the point on the circle in direction dir from the center (support point)
+        self.center + (dir.normalize() * self.radius)
+    }
+
+    pub fn farthest_from(&self, p: Point) -> Point {
+        // the point on the circle farthest from p
+        // every point is equally far from the center, so pick angle zero
+        if p == self.center {
+            self.at_ang(0.0)
+        } else {
+            self.point_in_direction(self.center - p)
+        }
+    }
+
+    pub fn power(&self, p: Point) -> f64 {
+        // power of p with respect to the circle
+        // negative inside, zero on the circle, positive outside
+        (p - self.center).dot(p - self.center) - self.radius.powi(2)
+    }
+
+    pub fn contains(&self, p: Point) -> bool {
+        // strictly inside, so points on the circle itself are not contained
+        p.dist(self.center) < self.radius
+    }
+
+    pub fn contains_eq(&self, p: Point, tol: f64) -> bool {
+        // inside, or within tol of the circle
+        p.dist(self.center) <= self.radius + tol
+    }
+
+    pub fn on_boundary(&self, p: Point, tol: f64) -> bool {
+        // within tol of the circle itself, see Dist
+        self.dist(p) <= tol
+    }
+
+    pub fn radical_axis(&self, other: &Circle) -> Option<Line> {
+        // the line of points with equal power with respect to both circles
+        // |p - c1|^2 - r1^2 = |p - c2|^2 - r2^2
+        // => 2(c2 - c1).p = |c2|^2 - |c1|^2 + r1^2 - r2^2
+        let (c1, c2) = (self.center, other.center);
+        if c1 == c2 {
+            return None;
+        }
+
+        let a = 2.0 * (c2.x - c1.x);
+        let b = 2.0 * (c2.y - c1.y);
+        let c = c2.dot(c2) - c1.dot(c1) + self.radius.powi(2) - other.radius.powi(2);
+        Some(Line::new(a, b, c))
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct Ellipse {
+    pub(crate) center: Point,
+    pub(crate) semi_major: f64,
+    pub(crate) semi_minor: f64,
+    // angle of the major axis from the x axis
+    pub(crate) rotation: f64,
+}
+
+impl Ellipse {
+    pub fn new(center: Point, semi_major: f64, semi_minor: f64, rotation: f64) -> Self {
+        // both semi-axes must be positive, a flat ellipse has no interior to divide by
+        assert!(semi_major >= semi_minor && semi_minor > 0.0);
+        Ellipse {
+            center,
+            semi_major,
+            semi_minor,
+            rotation,
+        }
+    }
+
+    pub fn center(&self) -> Point {
+        self.center
+    }
+
+    pub fn semi_major(&self) -> f64 {
+        self.semi_major
+    }
+
+    pub fn semi_minor(&self) -> f64 {
+        self.semi_minor
+    }
+
+    pub fn rotation(&self) -> f64 {
+        self.rotation
+    }
+
+    pub(crate) fn frame_coords(&self, p: Point) -> Point {
+        // coordinates of p along the major and minor axes, from the center
+        let (d, (sin, cos)) = (p - self.center, self.rotation.sin_cos());
+        Point::new(d.x * cos + d.y * sin, d.y * cos - d.x * sin)
+    }
+
+    pub(crate) fn world_coords(&self, p: Point) -> Point {
+        // inverse of frame_coords
+        let (sin, cos) = self.rotation.sin_cos();
+        self.center + Point::new(p.x * cos - p.y * sin, p.x * sin + p.y * cos)
+    }
+
+    pub fn point_at_ang(&self, ang: f64) -> Point {
+        // the point on the ellipse in direction ang from the center, as Circle::at_ang
+        let (a, b) = (self.semi_major, self.semi_minor);
+        let (sin, cos) = (ang - self.rotation).sin_cos();
+        let r = a * b / ((b * cos).powi(2) + (a * sin).powi(2)).sqrt();
+        self.world_coords(Point::new(r * cos, r * sin))
+    }
+
+    pub fn area(&self) -> f64 {
+        std::f64::consts::PI * self.semi_major * self.semi_minor
+    }
+
+    pub fn circumference(&self) -> f64 {
+        // ramanujan's approximation, exact for circles and within about 0.4% for a flat
+        // (zero minor axis) ellipse
+        let (a, b) = (self.semi_major, self.semi_minor);
+        std::f64::consts::PI * (3.0 * (a + b) - ((3.0 * a + b) * (a + 3.0 * b)).sqrt())
+    }
+
+    pub fn bounding_box(&self) -> Aabb {
+        let (a, b) = (self.semi_major, self.semi_minor);
+        let (sin, cos) = self.rotation.sin_cos();
+        let half = Vector::new(
+            ((a * cos).powi(2) + (b * sin).powi(2)).sqrt(),
+            ((a * sin).powi(2) + (b * cos).powi(2)).sqrt(),
+        );
+        Aabb::from_corners(self.center - half, self.center + half)
+    }
+
+    pub fn contains(&self, p: Point) -> bool {
+        // whether p lies inside or on the ellipse
+        let l = self.frame_coords(p);
+        (l.x / self.semi_major).powi(2) + (l.y / self.semi_minor).powi(2) <= 1.0
+    }
 }
 
 #[derive(Clone, Copy, Debug)]
@@ -213,8 +693,10 @@ pub struct Arc {
 
 impl Arc {
     pub fn from_center_ang(center: Point, radius: f64, p_ang: f64, q_ang: f64, ccw: bool) -> Self {
-        assert!((0.0 <= q_ang) && (std::f64::consts::TAU >= q_ang));
-        assert!((0.0 <= p_ang) && (std::f64::consts::TAU >= p_ang));
+        // panics on out of range angles, see try_from_center_ang
+        // degenerate radii are allowed here, for arcs collapsed onto their center
+        Self::check_ang(p_ang).unwrap();
+        Self::check_ang(q_ang).unwrap();
         Arc {
             center,
             radius,
@@ -224,6 +706,116 @@ impl Arc {
         }
     }
 
+    pub fn try_from_center_ang(
+        center: Point,
+        radius: f64,
+        p_ang: f64,
+        q_ang: f64,
+        ccw: bool,
+    ) -> Result<Self, SpaceMathError> {
+        // checked constructor, rejecting out of range angles and degenerate (non-positive) radii
+        if radius.is_nan() || radius <= 0.0 {
+            return Err(SpaceMathError::BadRadius(radius));
+        }
+        Self::check_ang(p_ang)?;
+        Self::check_ang(q_ang)?;
+
+        Ok(Self::from_center_ang(center, radius, p_ang, q_ang, ccw))
+    }
+
+    fn check_ang(ang: f64) -> Result<(), SpaceMathError> {
+        if (0.0..=std::f64::consts::TAU).contains(&ang) {
+            Ok(())
+        } else {
+            Err(SpaceMathError::AngleOutOfRange(ang))
+        }
+    }
+
+    pub fn from_endpoints(p: Point, q: Point, radius: f64, ccw: bool) -> Option<Self> {
+        // the minor (at most half circle) arc from p to q with the given radius and direction
+        // none if the radius is too small to span the chord
+        let half_chord = p.dist(q) / 2.0;
+        let spans = radius >= half_chord;
+        if half_chord == 0.0 || !spans {
+            return None;
+        }
+
+        // ccw minor arcs have their center to the left of pq
+        let h = (radius.powi(2) - half_chord.powi(2)).max(0.0).sqrt();
+        let left = (q - p).perp().normalize();
+        let center = if ccw {
+            p.mid(q) + left * h
+        } else {
+            p.mid(q) - left * h
+        };
+
+        Some(Self::from_center_ang(
+            center,
+            radius,
+            (p - center).ang(),
+            (q - center).ang(),
+            ccw,
+        ))
+    }
+
+    pub fn from_bulge(p: Point, q: Point, bulge: f64) -> Option<Self> {
+        // the arc from p to q with the given bulge, as in dxf polylines
+        // bulge is tan(included angle / 4), positive for ccw, so magnitudes above 1 give
+        // major arcs
+        // none for a zero bulge or coincident ends
+        let half_chord = p.dist(q) / 2.0;
+        if half_chord == 0.0 || bulge == 0.0 {
+            return None;
+        }
+
+        // the sagitta is |bulge| * half_chord, and the center lies radius - sagitta from the
+        // chord midpoint, on the left of pq for a ccw minor arc
+        let b = bulge.abs();
+        let radius = half_chord * (1.0 + b * b) / (2.0 * b);
+        let h = radius - b * half_chord;
+        let left = (q - p).perp().normalize();
+        let center = if bulge > 0.0 {
+            p.mid(q) + left * h
+        } else {
+            p.mid(q) - left * h
+        };
+
+        Some(Self::from_center_ang(
+            center,
+            radius,
+            (p - center).ang(),
+            (q - center).ang(),
+            bulge > 0.0,
+        ))
+    }
+
+    pub fn from_three_points(p: Point, mid: Point, q: Point) -> Option<Self> {
+        // the arc starting at p, passing through mid and ending at q
+        // none if the points are colinear
+        let ccw = match orient(p, mid, q) {
+            Orient::Positive => true,
+            Orient::Negative => false,
+            Orient::Zero => return None,
+        };
+
+        let bisect_a = Segment::perp_bisect(Segment::new(p, mid));
+        let bisect_b = Segment::perp_bisect(Segment::new(mid, q));
+        let center = bisect_a.intersects_at(&bisect_b).get_one()?;
+
+        Some(Self::from_center_ang(
+            center,
+            center.dist(p),
+            (p - center).ang(),
+            (q - center).ang(),
+            ccw,
+        ))
+    }
+
+    pub fn is_degenerate(&self) -> bool {
+        // a zero-radius arc collapses onto its center
+        self.radius == 0.0
+    }
+
     pub fn to_circle(self) -> Circle {
         Circle {
             center: self.center,
@@ -240,7 +832,12 @@ impl Arc {
     }
 
     pub fn arc_length(&self) -> f64 {
-        (self.radius * (self.q_ang - self.p_ang)).abs()
+        if self.is_degenerate() {
+            return 0.0;
+        }
+
+        let (p_ang, q_ang) = self.pq_ang_unbounded();
+        (self.radius * (q_ang - p_ang)).abs()
     }
 
     pub fn p_ang(&self) -> f64 {
@@ -251,6 +848,10 @@ impl Arc {
         self.q_ang
     }
 
+    pub fn ccw(&self) -> bool {
+        self.ccw
+    }
+
     pub fn pq_ang_unbounded(&self) -> (f64, f64) {
         // give a q greater than p if arc is ccw
         // and a p greater than q if arc is !ccw
@@ -272,6 +873,28 @@ impl Arc {
         }
     }
 
+    pub fn split_at_ang(self, ang: f64) -> (Arc, Arc) {
+        // the pieces either side of the direction ang from the center, which should lie
+        // strictly within the arc, as a piece with equal end angles is a full circle
+        let ang = ang.rem_euclid(std::f64::consts::TAU);
+        (
+            Arc::from_center_ang(self.center, self.radius, self.p_ang, ang, self.ccw),
+            Arc::from_center_ang(self.center, self.radius, ang, self.q_ang, self.ccw),
+        )
+    }
+
+    pub fn sweep(&self) -> f64 {
+        // signed angle swept from p to q, positive if ccw and negative if not
+        // equal end angles sweep a full turn, as in pq_ang_unbounded
+        let (p_ang, q_ang) = self.pq_ang_unbounded();
+        q_ang - p_ang
+    }
+
+    pub fn included_angle(&self) -> f64 {
+        // unsigned angle swept from p to q, in (0, 2pi]
+        self.sweep().abs()
+    }
+
     pub fn p(&self) -> Point {
         (Point::unit(self.p_ang) * self.radius) + self.center
     }
@@ -292,8 +915,14 @@ impl Arc {
     pub fn bounds_contain(&self, r: Point) -> bool {
         // find whether r lies on the wedge described by center, p, and q
         // useful for determining whether a coradial r lies in the arc
+        // angular only - the distance from the center is ignored, see in_sector
         let ang = (r - self.center).ang();
 
+        if self.p_ang == self.q_ang {
+            // a full circle, either way round
+            return true;
+        }
+
         let is_in_ccw_arc = if self.p_ang < self.q_ang {
             self.p_ang <= ang && ang <= self.q_ang
         } else {
@@ -309,10 +938,59 @@ impl Arc {
         }
     }
 
+    pub fn bounds_contain_tol(&self, r: Point, tol: f64) -> bool {
+        // as bounds_contain, but also accepting directions within tol (measured along the
+        // arc) of either end, so hits at the ends survive rounding
+        if self.bounds_contain(r) {
+            return true;
+        }
+        let on_circle = self.to_circle().at_ang((r - self.center).ang());
+        on_circle.dist(self.p()) <= tol || on_circle.dist(self.q()) <= tol
+    }
+
+    pub fn offset(self, dist: f64) -> Arc {
+        // concentric arc dist to the right of the direction of travel (left if negative)
+        // the radius is clamped at zero, collapsing onto the center
+        let radius = if self.ccw {
+            self.radius + dist
+        } else {
+            self.radius - dist
+        };
+        Arc {
+            radius: radius.max(0.0),
+            ..self
+        }
+    }
+
+    pub fn midpoint(&self) -> Point {
+        // the point halfway along the arc, in its direction of travel
+        let (p_ang, q_ang) = self.pq_ang_unbounded();
+        self.to_circle().at_ang((p_ang + q_ang) / 2.0)
+    }
+
+    pub fn tangent_at(&self, p: Point) -> Vector {
+        // unit tangent at p (assumed to be on the arc), pointing in the direction of travel
+        let radial = (p - self.center).normalize();
+        if self.ccw {
+            radial.perp()
+        } else {
+            -radial.perp()
+        }
+    }
+
+    pub fn in_sector(&self, r: Point) -> bool {
+        // find whether r lies inside the pie slice bounded by the arc and its radii
+        (r - self.center).norm() <= self.radius && self.bounds_contain(r)
+    }
+
     pub fn sample_points(&self, n: usize) -> Vec<Point> {
         // sample evenly space points from the arc, with a minimum of two
         assert!(n >= 2);
 
+        if self.is_degenerate() {
+            return vec![self.center; n];
+        }
+
         // use unbounded, direction-corrected p and q angles
         let (p_ang, q_ang) = self.pq_ang_unbounded();
 
@@ -328,6 +1006,41 @@ impl Arc {
         res
     }
 
+    pub fn flatten_to_tolerance(&self, max_sag: f64) -> Vec<Segment> {
+        // approximate the arc with chords deviating from it by at most max_sag
+        // a chord spanning angle t has sagitta r(1 - cos(t/2))
+        assert!(max_sag > 0.0);
+
+        let (p_ang, q_ang) = self.pq_ang_unbounded();
+        let sweep = (q_ang - p_ang).abs();
+
+        let n = if self.is_degenerate() {
+            1
+        } else {
+            let max_ang = 2.0 * (1.0 - max_sag / self.radius).max(-1.0).acos();
+            ((sweep / max_ang).ceil() as usize).max(1)
+        };
+
+        self.sample_points(n + 1)
+            .windows(2)
+            .map(|pq| Segment::new(pq[0], pq[1]))
+            .collect()
+    }
+
+    pub fn reflect(&self, line: &Line) -> Arc {
+        // mirror image of the arc across the line, running from the mirrored p to the
+        // mirrored q
+        // mirroring flips handedness, so the arc turns the other way
+        let center = line.reflect(self.center);
+        Arc {
+            center,
+            p_ang: (line.reflect(self.p()) - center).ang(),
+            q_ang: (line.reflect(self.q()) - center).ang(),
+            ccw: !self.ccw,
+            ..*self
+        }
+    }
+
     pub fn reverse(&self) -> Self {
         Self {
             ccw: !self.ccw,
@@ -337,3 +1050,741 @@ impl Arc {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[should_panic]
+    fn ellipse_flat() {
+        Ellipse::new((0.0, 0.0).into(), 2.0, 0.0, 0.0);
+    }
+
+    #[test]
+    fn ellipse_measures() {
+        use std::f64::consts::{FRAC_PI_2, FRAC_PI_4, PI};
+        let tol = 1e-12;
+
+        let e = Ellipse::new((1.0, -1.0).into(), 3.0, 2.0, 0.0);
+        assert!((e.area() - 6.0 * PI).abs() < tol);
+        assert!(e.point_at_ang(0.0).eps_eq((4.0, -1.0).into(), tol));
+        assert!(e.point_at_ang(FRAC_PI_2).eps_eq((1.0, 1.0).into(), tol));
+
+        let bbox = e.bounding_box();
+        assert!(bbox.min.eps_eq((-2.0, -3.0).into(), tol));
+        assert!(bbox.max.eps_eq((4.0, 1.0).into(), tol));
+
+        assert!(e.contains((3.5, -0.5).into()));
+        assert!(!e.contains((3.5, 0.5).into()));
+
+        // a circle is an ellipse
+        let c = Ellipse::new((0.0, 0.0).into(), 2.0, 2.0, 0.3);
+        assert!((c.circumference() - 4.0 * PI).abs() < tol);
+
+        // points found by direction lie on the ellipse, turned with it
+        let r = Ellipse::new((2.0, 0.0).into(), 3.0, 1.0, FRAC_PI_4);
+        for k in 0..8 {
+            let p = r.point_at_ang(k as f64 * 0.75);
+            assert!(r.dist(p) < 1e-9);
+            assert!(((p - r.center()).ang() - (k as f64 * 0.75)).abs() < 1e-9);
+        }
+        let tip = r.center() + Point::unit(FRAC_PI_4) * 3.0;
+        assert!(r.point_at_ang(FRAC_PI_4).eps_eq(tip, tol));
+        assert!(r
+            .bounding_box()
+            .max
+            .eps_eq(r.center() + Point::new(5.0f64.sqrt(), 5.0f64.sqrt()), tol));
+    }
+
+    #[test]
+    fn ellipse_dist() {
+        let e = Ellipse::new((0.0, 0.0).into(), 2.0, 1.0, 0.0);
+        let tol = 1e-9;
+
+        assert!((e.dist((5.0, 0.0).into()) - 3.0).abs() < tol);
+        assert!((e.dist((0.0, -3.0).into()) - 2.0).abs() < tol);
+        assert!((e.dist((0.0, 0.0).into()) - 1.0).abs() < tol);
+
+        // inside, near the end of the major axis, the closest points are off the axis
+        let p = Point::new(1.8, 0.0);
+        let brute = (0..100_000)
+            .map(|k| {
+                e.point_at_ang(k as f64 * std::f64::consts::TAU / 100_000.0)
+                    .dist(p)
+            })
+            .fold(f64::INFINITY, f64::min);
+        assert!((e.dist(p) - brute).abs() < 1e-6);
+        assert!(e.dist(p) < 0.2);
+
+        // a general point, with the ellipse turned and moved
+        let turned = Ellipse::new((1.0, 2.0).into(), 2.0, 1.0, 1.0);
+        let p = turned.world_coords(Point::new(1.5, 1.2));
+        let brute = (0..100_000)
+            .map(|k| {
+                turned
+                    .point_at_ang(k as f64 * std::f64::consts::TAU / 100_000.0)
+                    .dist(p)
+            })
+            .fold(f64::INFINITY, f64::min);
+        assert!((turned.dist(p) - brute).abs() < 1e-6);
+    }
+
+    #[test]
+    fn circle_power() {
+        let c = Circle::new((1.0, 2.0).into(), 3.0);
+
+        assert!((c.power((1.0, 2.0).into()) + 9.0).abs() < 1e-9);
+        assert!(c.power((4.0, 2.0).into()).abs() < 1e-9);
+        assert!(c.power((5.0, 2.0).into()) > 0.0);
+    }
+
+    #[test]
+    fn circle_support_points() {
+        let c = Circle::new((0.0, 0.0).into(), 1.0);
+        assert!(
+            c.point_in_direction((1.0, 0.0).into())
+                .dist((1.0, 0.0).into())
+                < 1e-12
+        );
+        assert!(
+            c.point_in_direction((0.0, -5.0).into())
+                .dist((0.0, -1.0).into())
+                < 1e-12
+        );
+
+        let c = Circle::new((1.0, 1.0).into(), 2.0);
+        assert!(c.farthest_from((1.0, 0.0).into()).dist((1.0, 3.0).into()) < 1e-12);
+        assert!((c.farthest_from(c.center).dist(c.center) - 2.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn circle_radical_axis() {
+        let c1 = Circle::new((0.0, 0.0).into(), 2.0);
+        let c2 = Circle::new((4.0, 2.0).into(), 2.0);
+        let axis = c1.radical_axis(&c2).unwrap();
+
+        // equal circles: the radical axis is the perpendicular bisector of the centers
+        let bisect = Segment::perp_bisect(Segment::new(c1.center, c2.center));
+        let p = bisect.projected((0.0, 0.0).into());
+        let q = bisect.projected((10.0, -3.0).into());
+        for r in [p, q] {
+            assert!((axis.a * r.x + axis.b * r.y - axis.c).abs() < 1e-9);
+        }
+
+        assert!(c1
+            .radical_axis(&Circle::new((0.0, 0.0).into(), 1.0))
+            .is_none());
+    }
+
+    #[test]
+    fn arc_in_sector() {
+        let a = Arc::from_center_ang(
+            (1.0, 1.0).into(),
+            2.0,
+            0.0,
+            std::f64::consts::FRAC_PI_2,
+            true,
+        );
+
+        assert!(a.in_sector((2.0, 2.0).into()));
+        // right angle, beyond the radius
+        assert!(!a.in_sector((3.0, 3.0).into()));
+        assert!(a.bounds_contain((3.0, 3.0).into()));
+        // right radius, wrong angle
+        assert!(!a.in_sector((0.0, 0.0).into()));
+
+        // full circles cover every angle, whichever way round they run
+        for ccw in [true, false] {
+            let full = Arc::from_center_ang((1.0, 1.0).into(), 2.0, 1.0, 1.0, ccw);
+            assert!(full.bounds_contain((0.0, -3.0).into()));
+            assert!(full.in_sector((1.5, 1.0).into()));
+        }
+    }
+
+    fn on_line(l: Line, p: Point) -> bool {
+        (l.a * p.x + l.b * p.y - l.c).abs() < 1e-9
+    }
+
+    #[test]
+    fn line_slope_forms() {
+        let l = Line::from_slope_intercept(2.0, 1.0);
+        assert_eq!((l.a, l.b, l.c), (-2.0, 1.0, 1.0));
+        assert!(on_line(l, (0.0, 1.0).into()));
+        assert!(on_line(l, (1.0, 3.0).into()));
+
+        let p = Point::new(2.0, -1.0);
+        let l = Line::from_point_slope(p, 0.5);
+        assert_eq!((l.a, l.b, l.c), (-0.5, 1.0, -2.0));
+        assert!(on_line(l, p));
+        assert!(on_line(l, (4.0, 0.0).into()));
+
+        let l = Line::from_point_slope(p, f64::INFINITY);
+        assert_eq!((l.a, l.b, l.c), (1.0, 0.0, 2.0));
+        assert!(on_line(l, (2.0, 10.0).into()));
+    }
+
+    #[test]
+    fn line_from_points() {
+        let (p, q) = (Point::new(1.0, 1.0), Point::new(3.0, 5.0));
+        let l = Line::from_points(p, q);
+        assert_eq!((l.a, l.b, l.c), (4.0, -2.0, 2.0));
+        assert!(on_line(l, p));
+        assert!(on_line(l, q));
+
+        // vertical
+        let (p, q) = (Point::new(-1.0, 0.0), Point::new(-1.0, 2.0));
+        let l = Line::from_points(p, q);
+        assert!(l.a.is_finite() && l.b.is_finite() && l.c.is_finite());
+        assert!(on_line(l, p));
+        assert!(on_line(l, q));
+    }
+
+    #[test]
+    fn line_normalized() {
+        let l = Line::new(-3.0, -4.0, 10.0).normalized();
+        assert!((l.a - 0.6).abs() < 1e-12);
+        assert!((l.b - 0.8).abs() < 1e-12);
+        assert!((l.c + 2.0).abs() < 1e-12);
+
+        // vertical
+        let l = Line::new(-2.0, 0.0, 4.0).normalized();
+        assert_eq!((l.a, l.b, l.c), (1.0, 0.0, -2.0));
+
+        // horizontal
+        let l = Line::new(0.0, -5.0, 5.0).normalized();
+        assert_eq!((l.a, l.b, l.c), (0.0, 1.0, -1.0));
+
+        // the same line at a tiny scale normalizes the same way
+        let big = Line::new(3.0, -4.0, 10.0).normalized();
+        let tiny = Line::new(3e-13, -4e-13, 1e-12).normalized();
+        assert!((big.a - tiny.a).abs() < 1e-12);
+        assert!((big.b - tiny.b).abs() < 1e-12);
+        assert!((big.c - tiny.c).abs() < 1e-12);
+    }
+
+    #[test]
+    fn line_slope_angle() {
+        let l = Line::from_points((0.0, 0.0).into(), (1.0, 1.0).into());
+        assert!((l.slope().unwrap() - 1.0).abs() < 1e-12);
+        assert!((l.angle() - std::f64::consts::FRAC_PI_4).abs() < 1e-12);
+
+        let l = Line::from_points((0.0, 0.0).into(), (1.0, -1.0).into());
+        assert!((l.slope().unwrap() + 1.0).abs() < 1e-12);
+        assert!((l.angle() - 3.0 * std::f64::consts::FRAC_PI_4).abs() < 1e-12);
+
+        let l = Line::from_points((2.0, 0.0).into(), (2.0, 1.0).into());
+        assert_eq!(l.slope(), None);
+        assert!((l.angle() - std::f64::consts::FRAC_PI_2).abs() < 1e-12);
+    }
+
+    #[test]
+    fn ray_from_points() {
+        let r = Ray::from_points((1.0, 1.0).into(), (1.0, 0.0).into());
+        assert!((r.ang - 1.5 * std::f64::consts::PI).abs() < 1e-12);
+    }
+
+    #[test]
+    fn ray_reflect() {
+        use std::f64::consts::FRAC_PI_4;
+
+        let r = Ray::new((0.0, 0.0).into(), FRAC_PI_4);
+        let wall = Line::new(1.0, 0.0, 2.0);
+
+        let reflected = r.reflect(&wall);
+        assert!(reflected.init.dist((2.0, 2.0).into()) < 1e-9);
+        assert!((reflected.ang - 3.0 * FRAC_PI_4).abs() < 1e-9);
+
+        // parallel to the wall, never hits it
+        let r = Ray::new((0.0, 0.0).into(), std::f64::consts::FRAC_PI_2);
+        let unchanged = r.reflect(&wall);
+        assert_eq!((unchanged.init, unchanged.ang), (r.init, r.ang));
+
+        // misses the segment
+        let r = Ray::new((0.0, 0.0).into(), FRAC_PI_4);
+        let s = Segment::new((2.0, -1.0).into(), (2.0, 1.0).into());
+        let unchanged = r.reflect_segment(&s);
+        assert_eq!((unchanged.init, unchanged.ang), (r.init, r.ang));
+    }
+
+    #[test]
+    fn arc_degenerate() {
+        let c = Point::new(1.0, 2.0);
+        assert_eq!(
+            Arc::try_from_center_ang(c, 0.0, 0.0, 1.0, true).unwrap_err(),
+            SpaceMathError::BadRadius(0.0)
+        );
+        assert_eq!(
+            Arc::try_from_center_ang(c, -1.0, 0.0, 1.0, true).unwrap_err(),
+            SpaceMathError::BadRadius(-1.0)
+        );
+        assert!(matches!(
+            Arc::try_from_center_ang(c, f64::NAN, 0.0, 1.0, true),
+            Err(SpaceMathError::BadRadius(_))
+        ));
+        assert_eq!(
+            Arc::try_from_center_ang(c, 1.0, 0.0, 7.0, true).unwrap_err(),
+            SpaceMathError::AngleOutOfRange(7.0)
+        );
+        assert_eq!(
+            Arc::try_from_center_ang(c, 1.0, -0.5, 1.0, true).unwrap_err(),
+            SpaceMathError::AngleOutOfRange(-0.5)
+        );
+        assert!(Arc::try_from_center_ang(c, 1.0, 0.0, 1.0, true).is_ok());
+
+        let a = Arc::from_center_ang(c, 0.0, 0.0, 1.0, true);
+        assert!(a.is_degenerate());
+        assert_eq!(a.arc_length(), 0.0);
+        assert!(a.sample_points(4).iter().all(|p| *p == c));
+    }
+
+    #[test]
+    fn segment_overlap_length() {
+        let a = Segment::new((0.0, 0.0).into(), (3.0, 4.0).into());
+        assert!((a.overlap_length(&a, 1e-9) - 5.0).abs() < 1e-9);
+        assert!((a.overlap_length(&a.reverse(), 1e-9) - 5.0).abs() < 1e-9);
+
+        let b = Segment::new((6.0, 8.0).into(), (1.5, 2.0).into());
+        assert!((a.overlap_length(&b, 1e-9) - 2.5).abs() < 1e-9);
+        assert!((b.overlap_length(&a, 1e-9) - 2.5).abs() < 1e-9);
+
+        // colinear but disjoint
+        let c = Segment::new((6.0, 8.0).into(), (9.0, 12.0).into());
+        assert_eq!(a.overlap_length(&c, 1e-9), 0.0);
+
+        // crossing
+        let d = Segment::new((0.0, 4.0).into(), (3.0, 0.0).into());
+        assert_eq!(a.overlap_length(&d, 1e-9), 0.0);
+    }
+
+    #[test]
+    fn arc_flatten_to_tolerance() {
+        for radius in [0.5, 2.0, 10.0, 100.0] {
+            for ccw in [true, false] {
+                let a = Arc::from_center_ang((1.0, -1.0).into(), radius, 0.5, 4.0, ccw);
+                let max_sag = 0.01;
+                let segments = a.flatten_to_tolerance(max_sag);
+
+                assert!(segments[0].p().dist(a.p()) < 1e-9);
+                assert!(segments.last().unwrap().q().dist(a.q()) < 1e-9);
+
+                // chords deviate most from the arc at their midpoints
+                for s in segments.iter() {
+                    let sag = radius - s.mid().dist(a.center());
+                    assert!(sag <= max_sag + 1e-12);
+                }
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn arc_flatten_zero_tolerance() {
+        let a = Arc::from_center_ang((0.0, 0.0).into(), 1.0, 0.5, 4.0, true);
+        a.flatten_to_tolerance(0.0);
+    }
+
+    #[test]
+    fn arc_from_endpoints() {
+        for ccw in [true, false] {
+            let a = Arc::from_center_ang((1.0, 2.0).into(), 3.0, 0.5, 2.0, ccw);
+            let (p_ang, q_ang) = a.pq_ang_unbounded();
+
+            let b = Arc::from_endpoints(a.p(), a.q(), a.radius(), a.ccw()).unwrap();
+            if (q_ang - p_ang).abs() <= std::f64::consts::PI {
+                // minor arc, so the same center
+                assert!(b.center().dist(a.center()) < 1e-9);
+            }
+            assert!(b.p().dist(a.p()) < 1e-9);
+            assert!(b.q().dist(a.q()) < 1e-9);
+            assert_eq!(b.ccw(), ccw);
+        }
+
+        let p = Point::new(0.0, 0.0);
+        let q = Point::new(2.0, 0.0);
+        assert!(Arc::from_endpoints(p, q, 0.9, true).is_none());
+        assert!(Arc::from_endpoints(p, p, 1.0, true).is_none());
+
+        // half circle from (0, 0) to (2, 0) ccw runs below the chord
+        let half = Arc::from_endpoints(p, q, 1.0, true).unwrap();
+        assert!(half.center().dist((1.0, 0.0).into()) < 1e-9);
+        assert!(half.sample_points(3)[1].dist((1.0, -1.0).into()) < 1e-9);
+    }
+
+    #[test]
+    fn arc_from_three_points() {
+        for ccw in [true, false] {
+            // 0.5 -> 5.0 is a major arc in the ccw direction, minor in the cw direction
+            let a = Arc::from_center_ang((1.0, 2.0).into(), 3.0, 0.5, 5.0, ccw);
+            let mid = a.sample_points(3)[1];
+
+            let b = Arc::from_three_points(a.p(), mid, a.q()).unwrap();
+            assert!(b.center().dist(a.center()) < 1e-9);
+            assert!((b.radius() - a.radius()).abs() < 1e-9);
+            assert!((b.p_ang() - a.p_ang()).abs() < 1e-9);
+            assert!((b.q_ang() - a.q_ang()).abs() < 1e-9);
+            assert_eq!(b.ccw(), ccw);
+        }
+
+        let colinear =
+            Arc::from_three_points((0.0, 0.0).into(), (1.0, 1.0).into(), (2.0, 2.0).into());
+        assert!(colinear.is_none());
+    }
+
+    #[test]
+    fn arc_midpoint_tangent() {
+        use std::f64::consts::FRAC_PI_2;
+
+        // wraps through angle zero
+        let a = Arc::from_center_ang((1.0, 1.0).into(), 2.0, 3.0 * FRAC_PI_2, FRAC_PI_2, true);
+        assert!(a.midpoint().dist((3.0, 1.0).into()) < 1e-9);
+        assert!(a.reverse().midpoint().dist((3.0, 1.0).into()) < 1e-9);
+
+        let b = Arc::from_center_ang((1.0, 1.0).into(), 2.0, 3.0 * FRAC_PI_2, FRAC_PI_2, false);
+        assert!(b.midpoint().dist((-1.0, 1.0).into()) < 1e-9);
+
+        for arc in [a, b] {
+            let t = arc.tangent_at(arc.p());
+            assert!((t.norm() - 1.0).abs() < 1e-9);
+            assert!(t.dot(arc.p() - arc.center()).abs() < 1e-9);
+
+            // a small step along the tangent moves towards the rest of the arc
+            let step = arc.p() + t * 1e-3;
+            assert!(step.dist(arc.midpoint()) < arc.p().dist(arc.midpoint()));
+        }
+
+        // starting at the bottom: ccw heads right, cw heads left
+        assert!(a.tangent_at(a.p()).eps_eq((1.0, 0.0).into(), 1e-9));
+        assert!(b.tangent_at(b.p()).eps_eq((-1.0, 0.0).into(), 1e-9));
+    }
+
+    #[test]
+    fn segment_offset() {
+        let s = Segment::new((0.0, 0.0).into(), (3.0, 4.0).into());
+        let o = s.offset(2.0);
+
+        // parallel, and 2 to the right of travel
+        assert!((o.q - o.p).cross(s.q - s.p).abs() < 1e-9);
+        assert!((s.to_line().dist(o.p) - 2.0).abs() < 1e-9);
+        assert!((s.to_line().dist(o.q) - 2.0).abs() < 1e-9);
+        assert!((s.q - s.p).cross(o.p - s.p) < 0.0);
+
+        let back = o.offset(-2.0);
+        assert!(back.p.dist(s.p) < 1e-9 && back.q.dist(s.q) < 1e-9);
+    }
+
+    #[test]
+    fn arc_offset() {
+        let a = Arc::from_center_ang((1.0, 1.0).into(), 2.0, 0.5, 2.0, true);
+
+        let o = a.offset(0.5);
+        assert_eq!(o.center(), a.center());
+        assert_eq!(
+            (o.p_ang(), o.q_ang(), o.ccw()),
+            (a.p_ang(), a.q_ang(), a.ccw())
+        );
+        assert_eq!(o.radius(), 2.5);
+
+        // right of travel is inward for a clockwise arc
+        assert_eq!(a.reverse().offset(0.5).radius(), 1.5);
+        assert_eq!(a.offset(-5.0).radius(), 0.0);
+    }
+
+    #[test]
+    fn arc_length_wraps() {
+        use std::f64::consts::{PI, TAU};
+
+        let full = Arc::from_center_ang((0.0, 0.0).into(), 2.0, 0.0, 0.0, true);
+        assert!((full.arc_length() - 2.0 * TAU).abs() < 1e-12);
+
+        // crosses angle zero
+        let a = Arc::from_center_ang((0.0, 0.0).into(), 1.0, 1.5 * PI, 0.5 * PI, true);
+        assert!((a.arc_length() - PI).abs() < 1e-12);
+        assert!((a.reverse().arc_length() - PI).abs() < 1e-12);
+    }
+
+    #[test]
+    fn segment_ray_clamp() {
+        use super::super::dist::Dist;
+
+        let s = Segment::new((1.0, 1.0).into(), (3.0, 1.0).into());
+        // interior points project to the foot of the perpendicular
+        assert!(s.clamp((2.0, 5.0).into()).eps_eq((2.0, 1.0).into(), 1e-12));
+        // out of bounds points clamp to the nearest end
+        assert!(s.clamp((-4.0, 0.0).into()).eps_eq(s.p(), 1e-12));
+        assert!(s.clamp((7.0, 3.0).into()).eps_eq(s.q(), 1e-12));
+        assert!((s.dist((7.0, 4.0).into()) - 5.0).abs() < 1e-12);
+
+        let point = Segment::new((1.0, 1.0).into(), (1.0, 1.0).into());
+        assert_eq!(point.clamp((4.0, 5.0).into()), Point::new(1.0, 1.0));
+
+        let r = Ray::from_points((0.0, 0.0).into(), (1.0, 1.0).into());
+        assert!(r.clamp((0.0, 2.0).into()).eps_eq((1.0, 1.0).into(), 1e-12));
+        assert!(r.clamp((-1.0, 0.0).into()).eps_eq((0.0, 0.0).into(), 1e-12));
+        assert!((r.dist((-3.0, -4.0).into()) - 5.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn reflect_across_line() {
+        let x_axis = Line::new(0.0, 1.0, 0.0);
+        assert!(x_axis
+            .reflect((2.0, 3.0).into())
+            .eps_eq((2.0, -3.0).into(), 1e-12));
+
+        // y = x swaps coordinates
+        let diag = Line::from_points((0.0, 0.0).into(), (1.0, 1.0).into());
+        assert!(diag
+            .reflect((2.0, -1.0).into())
+            .eps_eq((-1.0, 2.0).into(), 1e-12));
+
+        // points on the line stay put
+        assert!(diag
+            .reflect((3.0, 3.0).into())
+            .eps_eq((3.0, 3.0).into(), 1e-12));
+
+        let s = Segment::new((1.0, 0.0).into(), (4.0, 2.0).into()).reflect(&diag);
+        assert!(s.p().eps_eq((0.0, 1.0).into(), 1e-12));
+        assert!(s.q().eps_eq((2.0, 4.0).into(), 1e-12));
+    }
+
+    #[test]
+    fn arc_sweep() {
+        use std::f64::consts::{FRAC_PI_2, PI, TAU};
+        let tol = 1e-12;
+
+        // ccw, crossing zero from 3pi/2 round to pi/2
+        let arc = Arc::from_center_ang((1.0, 2.0).into(), 2.0, 3.0 * FRAC_PI_2, FRAC_PI_2, true);
+        assert!((arc.sweep() - PI).abs() < tol);
+        assert!((arc.included_angle() - arc.arc_length() / arc.radius()).abs() < tol);
+
+        // cw, from pi/2 down through zero to 7pi/4
+        let arc = Arc::from_center_ang((0.0, 0.0).into(), 3.0, FRAC_PI_2, 7.0 * PI / 4.0, false);
+        assert!((arc.sweep() + 3.0 * PI / 4.0).abs() < tol);
+        assert!((arc.included_angle() - 3.0 * PI / 4.0).abs() < tol);
+        assert!((arc.included_angle() - arc.arc_length() / arc.radius()).abs() < tol);
+
+        // cw without wrapping
+        let arc = Arc::from_center_ang((0.0, 0.0).into(), 1.0, PI, FRAC_PI_2, false);
+        assert!((arc.sweep() + FRAC_PI_2).abs() < tol);
+
+        // equal ends sweep a full turn
+        let arc = Arc::from_center_ang((0.0, 0.0).into(), 1.0, 1.0, 1.0, true);
+        assert!((arc.included_angle() - TAU).abs() < tol);
+    }
+
+    #[test]
+    fn arc_from_bulge() {
+        use std::f64::consts::PI;
+
+        let (p, q) = ((1.0, 0.0).into(), (0.0, 1.0).into());
+
+        // a quarter circle has bulge tan(pi / 8)
+        for ccw in [true, false] {
+            let bulge = if ccw {
+                (PI / 8.0).tan()
+            } else {
+                -(PI / 8.0).tan()
+            };
+            let a = Arc::from_bulge(p, q, bulge).unwrap();
+            let b = Arc::from_endpoints(p, q, 1.0, ccw).unwrap();
+            assert!(a.center().dist(b.center()) < 1e-12);
+            assert!((a.radius() - b.radius()).abs() < 1e-12);
+            assert_eq!(a.ccw(), b.ccw());
+            assert!(a.p().dist(p) < 1e-12 && a.q().dist(q) < 1e-12);
+        }
+
+        // a bulge above one is a major arc
+        let a = Arc::from_bulge(p, q, 2.0).unwrap();
+        let theta = 4.0 * 2.0f64.atan();
+        assert!(theta > PI);
+        assert!((a.arc_length() - theta * a.radius()).abs() < 1e-12);
+
+        assert!(Arc::from_bulge(p, q, 0.0).is_none());
+        assert!(Arc::from_bulge(p, p, 1.0).is_none());
+    }
+
+    #[test]
+    fn line_relations() {
+        use std::f64::consts::{FRAC_PI_2, FRAC_PI_4};
+        let tol = 1e-9;
+
+        let flat = Line::new(0.0, 1.0, 2.0);
+        let also_flat = Line::new(0.0, -3.0, 1.0);
+        let upright = Line::new(2.0, 0.0, 5.0);
+        let diag = Line::from_points((0.0, 0.0).into(), (1.0, 1.0).into());
+
+        assert!(flat.is_parallel(&also_flat, tol));
+        assert!(flat.is_parallel(&flat, tol));
+        assert!(!flat.is_perpendicular(&also_flat, tol));
+        assert!(flat.angle_between(&also_flat).abs() < tol);
+
+        assert!(flat.is_perpendicular(&upright, tol));
+        assert!(!flat.is_parallel(&upright, tol));
+        assert!((flat.angle_between(&upright) - FRAC_PI_2).abs() < tol);
+
+        assert!(!diag.is_parallel(&flat, tol) && !diag.is_perpendicular(&flat, tol));
+        assert!((diag.angle_between(&flat) - FRAC_PI_4).abs() < tol);
+        assert!((diag.angle_between(&upright) - FRAC_PI_4).abs() < tol);
+
+        // nearly parallel, within a loose tolerance only
+        let tilted = Line::from_points((0.0, 2.0).into(), (1000.0, 2.001).into());
+        assert!(tilted.is_parallel(&flat, 1e-5));
+        assert!(!tilted.is_parallel(&flat, 1e-7));
+    }
+
+    #[test]
+    fn line_fit() {
+        // noisy points along y = 2x + 1, with a steep slope to catch ordinary least squares
+        let noise = [
+            0.03, -0.02, 0.01, -0.04, 0.02, 0.0, -0.01, 0.03, -0.03, 0.01,
+        ];
+        let points: Vec<Point> = noise
+            .iter()
+            .enumerate()
+            .map(|(i, e)| {
+                let x = i as f64 * 0.5;
+                Point::new(x - 2.0 * e, 2.0 * x + 1.0 + e)
+            })
+            .collect();
+
+        let fit = Line::fit(&points).unwrap();
+        assert!((fit.a.powi(2) + fit.b.powi(2) - 1.0).abs() < 1e-12);
+        assert!((fit.angle() - 2.0f64.atan()).abs() < 1e-2);
+        assert!(fit.dist((0.0, 1.0).into()) < 0.05);
+
+        // vertical
+        let vertical: Vec<Point> = (0..5).map(|i| Point::new(3.0, i as f64)).collect();
+        let fit = Line::fit(&vertical).unwrap();
+        assert!((fit.angle() - std::f64::consts::FRAC_PI_2).abs() < 1e-12);
+        assert!(fit.dist((3.0, 10.0).into()) < 1e-12);
+
+        assert!(Line::fit(&[(1.0, 1.0).into()]).is_none());
+        assert!(Line::fit(&[(1.0, 1.0).into(), (1.0, 1.0).into()]).is_none());
+    }
+
+    #[test]
+    fn split_pieces() {
+        let s = Segment::new((1.0, 1.0).into(), (4.0, 5.0).into());
+        let (a, b) = s.split_at_t(0.4);
+        assert_eq!((a.p(), b.q()), (s.p(), s.q()));
+        assert_eq!(a.q(), b.p());
+        assert!(a.q().eps_eq((2.2, 2.6).into(), 1e-12));
+        assert!((a.p().dist(a.q()) + b.p().dist(b.q()) - 5.0).abs() < 1e-12);
+
+        let (a, b) = s.split_at((2.5, 3.0).into());
+        assert_eq!((a.q(), b.p()), ((2.5, 3.0).into(), (2.5, 3.0).into()));
+        assert!((a.p().dist(a.q()) + b.p().dist(b.q()) - 5.0).abs() < 1e-12);
+
+        for ccw in [true, false] {
+            // crossing angle zero in either direction
+            let arc = Arc::from_center_ang((1.0, -1.0).into(), 2.0, 5.5, 1.0, ccw);
+            let ang = if ccw { 0.25 } else { 3.0 };
+            let (a, b) = arc.split_at_ang(ang);
+            assert!(a.p().eps_eq(arc.p(), 1e-12) && b.q().eps_eq(arc.q(), 1e-12));
+            assert!(a.q().eps_eq(b.p(), 1e-12));
+            assert!(a.q().eps_eq(arc.to_circle().at_ang(ang), 1e-12));
+            assert_eq!(a.ccw(), ccw);
+            assert!((a.arc_length() + b.arc_length() - arc.arc_length()).abs() < 1e-12);
+        }
+
+        // a full circle splits into two arcs, and angles are taken modulo a full turn
+        let full = Arc::from_center_ang(Point::origin(), 1.0, 0.0, 0.0, true);
+        let (a, b) = full.split_at_ang(-std::f64::consts::PI);
+        assert!((a.arc_length() - std::f64::consts::PI).abs() < 1e-12);
+        assert!((b.arc_length() - std::f64::consts::PI).abs() < 1e-12);
+    }
+
+    #[test]
+    fn circle_contains() {
+        let c = Circle::new(Point::origin(), 1.0);
+
+        let inside = Point::new(0.3, -0.4);
+        assert!(c.contains(inside) && c.contains_eq(inside, 1e-9));
+        assert!(!c.on_boundary(inside, 1e-9));
+
+        let on = Point::unit(2.0);
+        assert!(!c.contains((1.0, 0.0).into()));
+        assert!(c.contains_eq(on, 1e-9) && c.on_boundary(on, 1e-9));
+
+        let outside = Point::new(0.8, 0.8);
+        assert!(!c.contains(outside) && !c.contains_eq(outside, 1e-9));
+        assert!(!c.on_boundary(outside, 1e-9));
+        assert!(c.contains_eq(outside, 0.2) && c.on_boundary(outside, 0.2));
+
+        // the center is as far from the circle as possible
+        assert!(c.contains(Point::origin()));
+        assert!(!c.on_boundary(Point::origin(), 0.5));
+    }
+
+    #[test]
+    fn circle_min_enclosing() {
+        assert!(Circle::min_enclosing(&[]).is_none());
+
+        let one = Circle::min_enclosing(&[(1.0, 2.0).into()]).unwrap();
+        assert_eq!((one.center, one.radius), (Point::new(1.0, 2.0), 0.0));
+
+        // an acute triangle needs its circumcircle
+        let acute = [(0.0, 0.0).into(), (4.0, 0.0).into(), (2.0, 3.0).into()];
+        let c = Circle::min_enclosing(&acute).unwrap();
+        let tri = super::super::Triangle::from((acute[0], acute[1], acute[2]));
+        assert!(c.center.eps_eq(tri.circumcenter(), 1e-12));
+        assert!((c.radius - tri.circumradius()).abs() < 1e-12);
+
+        // an obtuse one only its longest side as a diameter
+        let obtuse = [(0.0, 0.0).into(), (4.0, 0.0).into(), (2.0, 0.5).into()];
+        let c = Circle::min_enclosing(&obtuse).unwrap();
+        assert!(c.center.eps_eq((2.0, 0.0).into(), 1e-12));
+        assert!((c.radius - 2.0).abs() < 1e-12);
+
+        // a cluster with one far outlier
+        let mut points: Vec<Point> = (0..200)
+            .map(|k| {
+                let k = k as f64;
+                Point::new((k * 0.37).sin(), (k * 0.91).cos()) * (1.0 + (k * 0.13).sin() * 0.5)
+            })
+            .collect();
+        points.push((20.0, 5.0).into());
+        let c = Circle::min_enclosing(&points).unwrap();
+        assert!(points.iter().all(|p| c.center.dist(*p) <= c.radius + 1e-9));
+
+        // it is no bigger than needed: at least two points lie on it, and it spans little more
+        // than the gap between the outlier and the cluster, within 1.5 of the origin
+        let on = points
+            .iter()
+            .filter(|p| (c.center.dist(**p) - c.radius).abs() < 1e-9)
+            .count();
+        assert!(on >= 2);
+        assert!(c.radius < 12.0);
+    }
+
+    #[test]
+    fn circle_fit() {
+        let noise = [
+            0.01, -0.02, 0.015, 0.0, -0.01, 0.02, -0.015, 0.005, 0.01, -0.005,
+        ];
+        let points: Vec<Point> = noise
+            .iter()
+            .enumerate()
+            .map(|(i, e)| {
+                let ang = i as f64 * 0.5;
+                Point::new(3.0, -1.0) + Point::unit(ang) * (2.0 + e)
+            })
+            .collect();
+
+        let fit = Circle::fit(&points).unwrap();
+        assert!(fit.center.dist((3.0, -1.0).into()) < 0.02);
+        assert!((fit.radius - 2.0).abs() < 0.02);
+
+        // exact on a noiseless arc
+        let arc: Vec<Point> = (0..4).map(|i| Point::unit(i as f64 * 0.3) * 5.0).collect();
+        let fit = Circle::fit(&arc).unwrap();
+        assert!(fit.center.dist(Point::origin()) < 1e-9);
+        assert!((fit.radius - 5.0).abs() < 1e-9);
+
+        let colinear: Vec<Point> = (0..5)
+            .map(|i| Point::new(i as f64, 2.0 * i as f64))
+            .collect();
+        assert!(Circle::fit(&colinear).is_none());
+        assert!(Circle::fit(&arc[..2]).is_none());
+    }
+}
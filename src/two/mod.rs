@@ -1,15 +1,50 @@
+pub mod aabb;
+pub mod approx;
+pub mod area;
+pub mod bezier;
 pub mod boundary;
+pub mod bytes;
+pub mod convex;
+pub mod curve;
 pub mod dist;
+pub mod dxf;
+pub mod error;
+#[cfg(feature = "geojson")]
+pub mod geojson;
+pub mod index;
 pub mod intersect;
 pub mod line;
+pub mod monotone;
+pub mod offset;
 pub mod point;
+pub mod polyline;
+pub mod predicates;
+pub mod region;
+pub mod scale;
 pub mod shift;
+pub mod svg;
 pub mod triangle;
+pub mod triangulate;
+pub mod vector;
+pub mod weld;
+pub mod wkt;
 
+pub use aabb::Aabb;
+pub use bezier::{CubicBezier, QuadBezier};
+pub use boundary::Boundary;
+pub use curve::Curve;
+pub use error::SpaceMathError;
 pub use line::Arc;
 pub use line::Circle;
+pub use line::Ellipse;
 pub use line::Line;
 pub use line::Ray;
 pub use line::Segment;
 pub use point::Point;
+pub use polyline::{Path, Polyline};
+pub use predicates::{incircle, orient2d};
+pub use region::Region;
+pub use triangle::orient;
+pub use triangle::orient3;
 pub use triangle::Triangle;
+pub use vector::Vector;
@@ -0,0 +1,430 @@
+// y-monotone decomposition of simple polygons, the plane sweep step before triangulation
+// polygons are given as boundaries, with any curved edges flattened into short chords
+
+use super::triangle::orient;
+use super::{Boundary, Point, Triangle};
+use crate::Orient;
+
+fn above(p: Point, q: Point) -> bool {
+    // sweep order: higher y first, ties broken by lower x
+    p.y > q.y || (p.y == q.y && p.x < q.x)
+}
+
+fn sweep_cmp(p: Point, q: Point) -> std::cmp::Ordering {
+    // the order of above as a total order, equal only for coincident points
+    q.y.partial_cmp(&p.y)
+        .unwrap()
+        .then(p.x.partial_cmp(&q.x).unwrap())
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum VertexKind {
+    Start,
+    Split,
+    End,
+    Merge,
+    Regular,
+}
+
+fn classify(points: &[Point], i: usize) -> VertexKind {
+    let n = points.len();
+    let (prev, v, next) = (points[(i + n - 1) % n], points[i], points[(i + 1) % n]);
+    let reflex = orient(prev, v, next) == Orient::Negative;
+
+    if above(v, prev) && above(v, next) {
+        if reflex {
+            VertexKind::Split
+        } else {
+            VertexKind::Start
+        }
+    } else if above(prev, v) && above(next, v) {
+        if reflex {
+            VertexKind::Merge
+        } else {
+            VertexKind::End
+        }
+    } else {
+        VertexKind::Regular
+    }
+}
+
+fn x_at(points: &[Point], e: usize, y: f64) -> f64 {
+    // x coordinate of edge e (from point e to e + 1) at height y
+    let (p, q) = (points[e], points[(e + 1) % points.len()]);
+    if p.y == q.y {
+        p.x.max(q.x)
+    } else {
+        p.x + (q.x - p.x) * (y - p.y) / (q.y - p.y)
+    }
+}
+
+fn diagonals(points: &[Point]) -> Vec<(usize, usize)> {
+    // the sweep from de berg et al., with the status kept as a plain list of (edge, helper)
+    // searching it linearly makes this O(n^2) in the worst case, fine for moderate inputs
+    let n = points.len();
+    let mut order: Vec<usize> = (0..n).collect();
+    order.sort_by(|&i, &j| sweep_cmp(points[i], points[j]));
+
+    let kinds: Vec<VertexKind> = (0..n).map(|i| classify(points, i)).collect();
+    let mut status: Vec<(usize, usize)> = Vec::new();
+    let mut res = Vec::new();
+
+    let left_of = |status: &Vec<(usize, usize)>, v: Point| {
+        // index into status of the edge directly left of v
+        status
+            .iter()
+            .enumerate()
+            .map(|(k, &(e, _))| (k, x_at(points, e, v.y)))
+            .filter(|&(_, x)| x <= v.x)
+            .max_by(|a, b| a.1.partial_cmp(&b.1).unwrap())
+            .map(|(k, _)| k)
+            .unwrap()
+    };
+
+    for &i in order.iter() {
+        let prev_edge = (i + n - 1) % n;
+        let v = points[i];
+
+        match kinds[i] {
+            VertexKind::Start => status.push((i, i)),
+            VertexKind::Split => {
+                let k = left_of(&status, v);
+                res.push((i, status[k].1));
+                status[k].1 = i;
+                status.push((i, i));
+            }
+            VertexKind::End | VertexKind::Merge => {
+                let k = status.iter().position(|&(e, _)| e == prev_edge).unwrap();
+                let helper = status[k].1;
+                if kinds[helper] == VertexKind::Merge {
+                    res.push((i, helper));
+                }
+                status.remove(k);
+
+                if kinds[i] == VertexKind::Merge {
+                    let k = left_of(&status, v);
+                    let helper = status[k].1;
+                    if kinds[helper] == VertexKind::Merge {
+                        res.push((i, helper));
+                    }
+                    status[k].1 = i;
+                }
+            }
+            VertexKind::Regular => {
+                if above(points[(i + n - 1) % n], v) {
+                    // descending along the left chain, interior to the right
+                    let k = status.iter().position(|&(e, _)| e == prev_edge).unwrap();
+                    let helper = status[k].1;
+                    if kinds[helper] == VertexKind::Merge {
+                        res.push((i, helper));
+                    }
+                    status.remove(k);
+                    status.push((i, i));
+                } else {
+                    let k = left_of(&status, v);
+                    let helper = status[k].1;
+                    if kinds[helper] == VertexKind::Merge {
+                        res.push((i, helper));
+                    }
+                    status[k].1 = i;
+                }
+            }
+        }
+    }
+
+    res
+}
+
+fn split_faces(points: &[Point], diagonals: &[(usize, usize)]) -> Vec<Vec<Point>> {
+    // walk the faces of the polygon cut along non-crossing diagonals
+    let n = points.len();
+
+    let mut out: Vec<Vec<usize>> = (0..n).map(|i| vec![(i + 1) % n]).collect();
+    for &(a, b) in diagonals {
+        out[a].push(b);
+        out[b].push(a);
+    }
+
+    let mut used = std::collections::HashSet::new();
+    let mut faces = Vec::new();
+
+    for start in 0..n {
+        for &first in out[start].clone().iter() {
+            if used.contains(&(start, first)) {
+                continue;
+            }
+
+            let mut face = vec![start];
+            let (mut u, mut v) = (start, first);
+            while v != start {
+                used.insert((u, v));
+                face.push(v);
+
+                // leave v by the edge turning the furthest right from the way back to u
+                let back = (points[u] - points[v]).ang();
+                let turn = |w: &usize| {
+                    let ang = back - (points[*w] - points[v]).ang();
+                    ang.rem_euclid(std::f64::consts::TAU)
+                };
+                let next = *out[v]
+                    .iter()
+                    .filter(|w| **w != u)
+                    .min_by(|a, b| turn(a).partial_cmp(&turn(b)).unwrap())
+                    .unwrap();
+
+                u = v;
+                v = next;
+            }
+            used.insert((u, v));
+
+            faces.push(face.into_iter().map(|i| points[i]).collect());
+        }
+    }
+
+    faces
+}
+
+pub fn decompose(poly: &Boundary) -> Vec<Boundary> {
+    // split a simple polygon into y-monotone pieces
+    // not yet O(n log n): the sweep status is a plain list, see diagonals
+    // a boundary flattening to fewer than three nodes encloses nothing, and is kept as is
+    let points = poly.flattened_points();
+    if points.len() < 3 {
+        return vec![poly.clone()];
+    }
+    let diagonals = diagonals(&points);
+
+    split_faces(&points, &diagonals)
+        .into_iter()
+        .map(|face| Boundary::from_points(&face))
+        .collect()
+}
+
+pub fn triangulate_monotone(poly: &Boundary) -> Vec<Triangle> {
+    // linear time stack triangulation of a y-monotone polygon
+    // triangles are returned counterclockwise
+    let points = poly.flattened_points();
+    let n = points.len();
+    if n < 3 {
+        return Vec::new();
+    }
+
+    // walking ccw from the top vertex runs down the left chain to the bottom vertex
+    let by = |f: fn(Point, Point) -> bool| {
+        (0..n)
+            .reduce(|a, b| if f(points[a], points[b]) { a } else { b })
+            .unwrap()
+    };
+    let top = by(above);
+    let bottom = by(|p, q| above(q, p));
+
+    let mut on_left = vec![false; n];
+    let mut i = top;
+    while i != bottom {
+        on_left[i] = true;
+        i = (i + 1) % n;
+    }
+
+    let mut order: Vec<usize> = (0..n).collect();
+    order.sort_by(|&i, &j| sweep_cmp(points[i], points[j]));
+
+    let mut res = Vec::new();
+    let mut push = |a: usize, b: usize, c: usize| {
+        let (a, b, c) = (points[a], points[b], points[c]);
+        if orient(a, b, c) == Orient::Negative {
+            res.push(Triangle::from((a, c, b)));
+        } else {
+            res.push(Triangle::from((a, b, c)));
+        }
+    };
+
+    let mut stack = vec![order[0], order[1]];
+    for &u in order[2..n - 1].iter() {
+        let top = *stack.last().unwrap();
+
+        if on_left[u] != on_left[top] {
+            // opposite chain: every stacked vertex can see u
+            for pair in stack.windows(2) {
+                push(u, pair[0], pair[1]);
+            }
+            stack = vec![top, u];
+        } else {
+            // same chain: cut off triangles while the corner they remove is convex
+            let mut last = stack.pop().unwrap();
+            while let Some(&prev) = stack.last() {
+                // the right chain runs the other way, so its convex turns are clockwise
+                let turn = orient(points[prev], points[last], points[u]);
+                let turn = if on_left[u] { turn } else { turn.reverse() };
+                if turn != Orient::Positive {
+                    break;
+                }
+
+                push(u, last, prev);
+                last = stack.pop().unwrap();
+            }
+            stack.push(last);
+            stack.push(u);
+        }
+    }
+
+    // the bottom vertex sees everything left on the stack
+    let u = order[n - 1];
+    for pair in stack.windows(2) {
+        push(u, pair[0], pair[1]);
+    }
+
+    res
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn is_monotone(poly: &Boundary) -> bool {
+        // walking around a y-monotone polygon, the sweep direction flips exactly twice
+        let points = poly.points();
+        let n = points.len();
+        let flips = (0..n)
+            .filter(|&i| {
+                let (prev, v, next) = (points[(i + n - 1) % n], points[i], points[(i + 1) % n]);
+                above(prev, v) != above(v, next)
+            })
+            .count();
+        flips == 2
+    }
+
+    #[test]
+    fn decompose_split_merge() {
+        // notched from below (a split vertex) and from above (a merge vertex)
+        let poly = Boundary::from_points(&[
+            (0.0, 0.0).into(),
+            (1.0, 0.0).into(),
+            (2.0, 1.5).into(),
+            (3.0, 0.0).into(),
+            (4.0, 0.0).into(),
+            (4.0, 4.0).into(),
+            (3.0, 4.0).into(),
+            (2.0, 2.5).into(),
+            (1.0, 4.0).into(),
+            (0.0, 4.0).into(),
+        ]);
+        assert!(!is_monotone(&poly));
+
+        let pieces = decompose(&poly);
+        assert_eq!(pieces.len(), 2);
+        assert!(pieces.iter().all(is_monotone));
+
+        let total: f64 = pieces.iter().map(|p| p.area()).sum();
+        assert!((total - poly.area()).abs() < 1e-9);
+
+        let original = poly.points();
+        for piece in pieces.iter() {
+            assert!(piece.area() > 0.0);
+            assert!(piece.points().iter().all(|p| original.contains(p)));
+        }
+    }
+
+    #[test]
+    fn decompose_monotone() {
+        let mut poly = Boundary::from_points(&[
+            (0.0, 0.0).into(),
+            (2.0, 1.0).into(),
+            (1.0, 3.0).into(),
+            (-1.0, 2.0).into(),
+        ]);
+        poly.reverse();
+
+        let pieces = decompose(&poly);
+        assert_eq!(pieces.len(), 1);
+        assert!((pieces[0].area().abs() - poly.area().abs()).abs() < 1e-9);
+    }
+
+    #[test]
+    fn decompose_curved() {
+        use crate::two::boundary::Edge;
+        use crate::two::{Arc, Segment};
+        use std::f64::consts::PI;
+
+        // a full circle is a single node before flattening, and monotone after
+        let circle = Boundary::new(vec![Arc::from_center_ang(
+            (0.0, 0.0).into(),
+            1.0,
+            0.0,
+            0.0,
+            true,
+        )]);
+        let pieces = decompose(&circle);
+        assert_eq!(pieces.len(), 1);
+        assert!(is_monotone(&pieces[0]));
+        assert!((pieces[0].area() - PI).abs() < 1e-3);
+        let tris = triangulate_monotone(&circle);
+        let total: f64 = tris
+            .iter()
+            .map(|t| {
+                let (a, b, c) = t.into_points();
+                Boundary::from_points(&[a, b, c]).area()
+            })
+            .sum();
+        assert!((total - PI).abs() < 1e-3);
+
+        // a square with its floor bulging up into it, peaking at (2, 1) to make a split vertex
+        // the chord alone would leave it monotone
+        let (p_ang, q_ang) = ((1.5f64).atan2(-2.0), (1.5f64).atan2(2.0));
+        let notched = Boundary::new::<_, Edge>(vec![
+            Arc::from_center_ang((2.0, -1.5).into(), 2.5, p_ang, q_ang, false).into(),
+            Segment::new((4.0, 0.0).into(), (4.0, 4.0).into()).into(),
+            Segment::new((4.0, 4.0).into(), (0.0, 4.0).into()).into(),
+            Segment::new((0.0, 4.0).into(), (0.0, 0.0).into()).into(),
+        ]);
+        assert!(is_monotone(&Boundary::from_points(&notched.points())));
+
+        let pieces = decompose(&notched);
+        assert!(pieces.len() >= 2);
+        assert!(pieces.iter().all(is_monotone));
+        let total: f64 = pieces.iter().map(|p| p.area()).sum();
+        assert!((total - notched.area()).abs() < 1e-2);
+    }
+
+    #[test]
+    fn triangulate() {
+        // a y-monotone polygon with reflex vertices on both chains
+        let poly = Boundary::from_points(&[
+            (0.0, 0.0).into(),
+            (3.0, 1.0).into(),
+            (2.0, 2.0).into(),
+            (3.5, 3.5).into(),
+            (2.5, 6.0).into(),
+            (1.0, 4.0).into(),
+            (1.5, 2.5).into(),
+            (-1.0, 1.5).into(),
+        ]);
+        assert!(is_monotone(&poly));
+
+        let tris = triangulate_monotone(&poly);
+        assert_eq!(tris.len(), 6);
+
+        let area = |t: &Triangle| {
+            let (a, b, c) = t.into_points();
+            Boundary::from_points(&[a, b, c]).area()
+        };
+        assert!(tris.iter().all(|t| area(t) > 0.0));
+
+        let total: f64 = tris.iter().map(area).sum();
+        assert!((total - poly.area()).abs() < 1e-9);
+
+        // no point is strictly inside two triangles
+        for i in -20..40 {
+            for j in 0..60 {
+                let p = Point::new(i as f64 * 0.1 + 0.05, j as f64 * 0.1 + 0.05);
+                let covering = tris
+                    .iter()
+                    .filter(|t| {
+                        let (a, b, c) = t.bary_coor(p);
+                        a > 1e-9 && b > 1e-9 && c > 1e-9
+                    })
+                    .count();
+                assert!(covering <= 1);
+            }
+        }
+    }
+}
@@ -0,0 +1,214 @@
+// polygon buffering: offsetting a whole boundary by a fixed distance
+
+use super::boundary::Edge;
+use super::dist::Dist;
+use super::intersect::Intersect;
+use super::{Arc, Boundary, Point, Segment, Vector};
+
+// TODO replace with some tolerance interface
+const TOL: f64 = 1e-7;
+
+fn start_tangent(e: &Edge) -> Vector {
+    match e {
+        Edge::Segment(s) => s.dir(),
+        Edge::Arc(a) => a.tangent_at(a.p()),
+        Edge::Bezier(b) => b.tangent_at(0.0).normalize(),
+    }
+}
+
+fn end_tangent(e: &Edge) -> Vector {
+    match e {
+        Edge::Segment(s) => s.dir(),
+        Edge::Arc(a) => a.tangent_at(a.q()),
+        Edge::Bezier(b) => b.tangent_at(1.0).normalize(),
+    }
+}
+
+fn raw_offset(bound: &Boundary, dist: f64) -> Vec<Edge> {
+    // offset every edge, then join neighbours
+    // corners that open a gap get a round join about the original node
+    // corners where the offsets overlap are routed back through the node, leaving a
+    // small reversed loop for the trimming step to discard
+    let mut res = Vec::new();
+    for (&e, &next) in bound.edge_pairs() {
+        let (from, to) = (e.offset(dist), next.offset(dist));
+        res.push(from);
+
+        let (a, b) = (from.q(), to.p());
+        if a.dist(b) < TOL {
+            continue;
+        }
+
+        let node = e.q();
+        let turn = end_tangent(&e).cross(start_tangent(&next));
+        if (turn > 0.0) == (dist > 0.0) {
+            let join = Arc::from_center_ang(
+                node,
+                dist.abs(),
+                (a - node).ang(),
+                (b - node).ang(),
+                turn > 0.0,
+            );
+            res.push(Edge::Arc(join));
+        } else {
+            res.push(Edge::Segment(Segment::new(a, node)));
+            res.push(Edge::Segment(Segment::new(node, b)));
+        }
+    }
+
+    res
+}
+
+impl Boundary {
+    pub fn offset(&self, dist: f64) -> Vec<Boundary> {
+        // grow the region enclosed by the boundary by dist (shrink if negative)
+        // convex corners are rounded, and any parts of the region narrower than the
+        // offset are dropped, so the result may be several boundaries (or none)
+        let mut bound = self.clone();
+        bound.orient_positive();
+
+        let raw = raw_offset(&bound, dist);
+
+        // split the raw curve wherever it crosses itself
+        let pieces: Vec<Edge> = raw
+            .iter()
+            .flat_map(|e| {
+                let crossings: Vec<Point> = raw
+                    .iter()
+                    .flat_map(|f| e.intersects_at(f).into_vec())
+                    .filter(|x| x.x.is_finite() && x.y.is_finite())
+                    .collect();
+                e.split_at(&crossings)
+            })
+            .filter(|e| e.length() > TOL)
+            .collect();
+
+        // keep the pieces lying the full distance from the original boundary
+        let mut kept: Vec<Option<Edge>> = pieces
+            .into_iter()
+            .filter(|e| self.dist(e.mid()) > dist.abs() - TOL)
+            .map(Some)
+            .collect();
+
+        // chain the surviving pieces back into closed loops
+        let mut res = Vec::new();
+        while let Some(first) = kept.iter_mut().find_map(|e| e.take()) {
+            let mut chain = vec![first];
+            loop {
+                let end = chain.last().unwrap().q();
+                if end.dist(chain[0].p()) < TOL {
+                    res.push(Boundary::new(chain));
+                    break;
+                }
+
+                match kept
+                    .iter_mut()
+                    .find(|e| e.is_some_and(|e| e.p().dist(end) < TOL))
+                {
+                    Some(e) => chain.push(e.take().unwrap()),
+                    // an open chain is left over from a numerical failure, drop it
+                    None => break,
+                }
+            }
+        }
+
+        res
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::f64::consts::PI;
+
+    fn square(side: f64) -> Boundary {
+        Boundary::from_points(&[
+            (0.0, 0.0).into(),
+            (side, 0.0).into(),
+            (side, side).into(),
+            (0.0, side).into(),
+        ])
+    }
+
+    #[test]
+    fn offset_square() {
+        let d = 0.5;
+        let grown = square(2.0).offset(d);
+        assert_eq!(grown.len(), 1);
+
+        // straight sides pushed out, with quarter circle corners
+        assert_eq!(grown[0].num_edges(), 8);
+        assert!((grown[0].area() - (4.0 + 8.0 * d + PI * d * d)).abs() < 1e-9);
+
+        let shrunk = square(2.0).offset(-d);
+        assert_eq!(shrunk.len(), 1);
+        assert!((shrunk[0].area() - 1.0).abs() < 1e-9);
+
+        // orientation of the input does not matter
+        let mut cw = square(2.0);
+        cw.reverse();
+        assert!((cw.offset(d)[0].area() - grown[0].area()).abs() < 1e-9);
+
+        assert!(square(2.0).offset(-1.5).is_empty());
+    }
+
+    #[test]
+    fn offset_concave() {
+        let l_shape = Boundary::from_points(&[
+            (0.0, 0.0).into(),
+            (2.0, 0.0).into(),
+            (2.0, 1.0).into(),
+            (1.0, 1.0).into(),
+            (1.0, 2.0).into(),
+            (0.0, 2.0).into(),
+        ]);
+
+        // five rounded convex corners, one mitered concave corner
+        let d = 0.25;
+        let grown = l_shape.offset(d);
+        assert_eq!(grown.len(), 1);
+        // the offset strips overlap by a d x d square at the concave corner
+        let expected = 3.0 + 8.0 * d + 5.0 * PI * d * d / 4.0 - d * d;
+        assert!((grown[0].area() - expected).abs() < 1e-9);
+        assert!(grown[0].is_simple());
+    }
+
+    #[test]
+    fn offset_splits() {
+        // two squares joined by a narrow neck, which vanishes when shrunk
+        let dumbbell = Boundary::from_points(&[
+            (0.0, 0.0).into(),
+            (2.0, 0.0).into(),
+            (2.0, 0.9).into(),
+            (3.0, 0.9).into(),
+            (3.0, 0.0).into(),
+            (5.0, 0.0).into(),
+            (5.0, 2.0).into(),
+            (3.0, 2.0).into(),
+            (3.0, 1.1).into(),
+            (2.0, 1.1).into(),
+            (2.0, 2.0).into(),
+            (0.0, 2.0).into(),
+        ]);
+
+        let shrunk = dumbbell.offset(-0.25);
+        assert_eq!(shrunk.len(), 2);
+        for b in shrunk.iter() {
+            // each square shrinks to 1.5 x 1.5, bulging slightly towards the old neck
+            assert!(b.area() > 1.5f64.powi(2));
+            assert!(b.area() < 1.5f64.powi(2) + 0.05);
+        }
+    }
+
+    #[test]
+    fn offset_circle() {
+        let edges = vec![Arc::from_center_ang((1.0, 1.0).into(), 2.0, 0.0, 0.0, true)];
+        let circle = Boundary::new(edges);
+
+        let grown = circle.offset(1.0);
+        assert_eq!(grown.len(), 1);
+        assert!((grown[0].area() - PI * 9.0).abs() < 1e-9);
+
+        assert!(circle.offset(-3.0).is_empty());
+    }
+}
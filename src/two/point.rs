@@ -1,8 +1,10 @@
 use std::fmt;
-use std::ops::{Add, Div, Mul, Sub};
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
 
 use super::dist::Dist;
+use super::Vector;
 
+// the derived PartialEq compares coordinates exactly, use eps_eq for tolerant comparisons
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub struct Point {
     pub x: f64,
@@ -10,6 +12,10 @@ pub struct Point {
 }
 
 impl Point {
+    pub const ZERO: Point = Point { x: 0.0, y: 0.0 };
+    pub const X: Point = Point { x: 1.0, y: 0.0 };
+    pub const Y: Point = Point { x: 0.0, y: 1.0 };
+
     pub fn new(x: f64, y: f64) -> Self {
         Point { x, y }
     }
@@ -21,8 +27,19 @@ impl Point {
         }
     }
 
+    pub fn from_polar(r: f64, ang: f64) -> Self {
+        // the point at distance r from the origin in direction ang
+        Point::unit(ang) * r
+    }
+
+    pub fn to_polar(self) -> (f64, f64) {
+        // (norm, ang), with ang in [0, 2pi) as for ang - the atan2 branch, shifted up by 2pi
+        // below the x axis
+        (self.norm(), self.ang())
+    }
+
     pub fn origin() -> Self {
-        Point { x: 0.0, y: 0.0 }
+        Point::ZERO
     }
 
     pub fn mid(&self, other: Point) -> Point {
@@ -33,6 +50,45 @@ impl Point {
         }
     }
 
+    pub fn lerp(self, other: Point, t: f64) -> Point {
+        // linear interpolation, self at t = 0 and other at t = 1
+        self + (other - self) * t
+    }
+
+    pub fn eps_eq(self, other: Point, epsilon: f64) -> bool {
+        // equal to within epsilon in each coordinate
+        (self.x - other.x).abs() <= epsilon && (self.y - other.y).abs() <= epsilon
+    }
+
+    pub fn quantize(self, grid: f64) -> (i64, i64) {
+        // index of the nearest node of a square grid with spacing grid, hashable unlike the point
+        (
+            (self.x / grid).round() as i64,
+            (self.y / grid).round() as i64,
+        )
+    }
+
+    pub fn min(self, other: Point) -> Point {
+        // componentwise minimum
+        Point {
+            x: self.x.min(other.x),
+            y: self.y.min(other.y),
+        }
+    }
+
+    pub fn max(self, other: Point) -> Point {
+        // componentwise maximum
+        Point {
+            x: self.x.max(other.x),
+            y: self.y.max(other.y),
+        }
+    }
+
+    pub fn clamp(self, lo: Point, hi: Point) -> Point {
+        // componentwise clamp into the box [lo, hi]
+        self.max(lo).min(hi)
+    }
+
     pub fn norm(self) -> f64 {
         (self.x.powi(2) + self.y.powi(2)).sqrt()
     }
@@ -66,9 +122,31 @@ impl Point {
         }
     }
 
+    pub fn cross(self, other: Point) -> f64 {
+        // z component of the 3d cross product, positive if other is counterclockwise of self
+        (self.x * other.y) - (self.y * other.x)
+    }
+
     // for use in gauss' area formula
     pub fn shoelace(self, other: Point) -> f64 {
-        (self.x * other.y) - (other.x * self.y)
+        self.cross(other)
+    }
+
+    pub fn angle_between(self, other: Point) -> f64 {
+        // unsigned angle between the two vectors, in [0, pi]
+        // from the cosine (dot over norms), with the sine (cross over norms) keeping it
+        // accurate near 0 and pi
+        self.cross(other).abs().atan2(self.dot(other))
+    }
+
+    pub fn signed_angle(self, other: Point) -> f64 {
+        // angle turning self onto other, in (-pi, pi], positive counterclockwise
+        let ang = self.cross(other).atan2(self.dot(other));
+        if ang == -std::f64::consts::PI {
+            std::f64::consts::PI
+        } else {
+            ang
+        }
     }
 
     pub fn transpose(self) -> Point {
@@ -79,6 +157,12 @@ impl Point {
     }
 }
 
+impl Default for Point {
+    fn default() -> Self {
+        Point::ZERO
+    }
+}
+
 impl fmt::Display for Point {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "(")?;
@@ -118,14 +202,12 @@ impl Add for Point {
     }
 }
 
+// the difference of two positions is the displacement between them, not another position
 impl Sub for Point {
-    type Output = Self;
+    type Output = Vector;
 
-    fn sub(self, other: Self) -> Self {
-        Point {
-            x: self.x - other.x,
-            y: self.y - other.y,
-        }
+    fn sub(self, other: Self) -> Vector {
+        Vector::between(other, self)
     }
 }
 
@@ -151,9 +233,186 @@ impl Div<f64> for Point {
     }
 }
 
+impl Neg for Point {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Point {
+            x: -self.x,
+            y: -self.y,
+        }
+    }
+}
+
+impl AddAssign for Point {
+    fn add_assign(&mut self, other: Self) {
+        self.x += other.x;
+        self.y += other.y;
+    }
+}
+
+impl SubAssign for Point {
+    fn sub_assign(&mut self, other: Self) {
+        self.x -= other.x;
+        self.y -= other.y;
+    }
+}
+
+impl MulAssign<f64> for Point {
+    fn mul_assign(&mut self, other: f64) {
+        self.x *= other;
+        self.y *= other;
+    }
+}
+
+impl DivAssign<f64> for Point {
+    fn div_assign(&mut self, other: f64) {
+        self.x /= other;
+        self.y /= other;
+    }
+}
+
 impl Dist for Point {
     fn dist(&self, other: Point) -> f64 {
         let v = *self - other;
         v.norm()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lerp() {
+        let (p, q) = (Point::new(-1.0, 2.0), Point::new(3.0, -4.0));
+
+        assert_eq!(p.lerp(q, 0.0), p);
+        assert_eq!(p.lerp(q, 1.0), q);
+        assert_eq!(p.lerp(q, 0.5), p.mid(q));
+        assert_eq!(p.lerp(q, 2.0), Point::new(7.0, -10.0));
+    }
+
+    #[test]
+    fn min_max_clamp() {
+        let (p, q) = (Point::new(-1.0, 2.0), Point::new(3.0, -4.0));
+
+        assert_eq!(p.min(q), Point::new(-1.0, -4.0));
+        assert_eq!(p.max(q), Point::new(3.0, 2.0));
+
+        let (lo, hi) = (Point::new(-2.0, -2.0), Point::new(2.0, 2.0));
+        assert_eq!(q.clamp(lo, hi), Point::new(2.0, -2.0));
+        assert_eq!(p.clamp(lo, hi), p);
+        assert_eq!(Point::new(-5.0, 0.5).clamp(lo, hi), Point::new(-2.0, 0.5));
+    }
+
+    #[test]
+    fn assign_ops() {
+        let p = Point::new(1.0, -2.0);
+        assert_eq!(-p, Point::new(-1.0, 2.0));
+
+        let mut q = p;
+        q += Point::new(2.0, 2.0);
+        assert_eq!(q, Point::new(3.0, 0.0));
+        q -= Point::new(1.0, 1.0);
+        assert_eq!(q, Point::new(2.0, -1.0));
+        q *= 3.0;
+        assert_eq!(q, Point::new(6.0, -3.0));
+        q /= 2.0;
+        assert_eq!(q, Point::new(3.0, -1.5));
+    }
+
+    #[test]
+    fn eps_eq() {
+        let p = Point::new(0.1 + 0.2, 1.0);
+        let q = Point::new(0.3, 1.0);
+
+        assert_ne!(p, q);
+        assert!(p.eps_eq(q, 1e-12));
+        assert!(!p.eps_eq(Point::new(0.3, 1.001), 1e-12));
+    }
+
+    #[test]
+    fn quantize() {
+        assert_eq!(Point::new(0.26, -0.74).quantize(0.5), (1, -1));
+        assert_eq!(
+            Point::new(0.1 + 0.2, 1.0).quantize(1e-9),
+            Point::new(0.3, 1.0).quantize(1e-9)
+        );
+    }
+
+    #[test]
+    fn polar() {
+        for &p in [
+            Point::new(1.0, 0.0),
+            Point::new(-2.0, 3.5),
+            Point::new(-1.5, -0.25),
+            Point::new(4.0, -4.0),
+        ]
+        .iter()
+        {
+            let (r, ang) = p.to_polar();
+            assert!((0.0..std::f64::consts::TAU).contains(&ang));
+            assert!(Point::from_polar(r, ang).eps_eq(p, 1e-12));
+        }
+
+        for &ang in [0.0, 1.0, 2.5, 4.0, -0.5].iter() {
+            assert_eq!(Point::from_polar(1.0, ang), Point::unit(ang));
+        }
+        assert_eq!(
+            Point::new(0.0, -2.0).to_polar(),
+            (2.0, 1.5 * std::f64::consts::PI)
+        );
+    }
+
+    #[test]
+    fn angles() {
+        use std::f64::consts::{FRAC_PI_2, FRAC_PI_4, PI};
+        let (x, y) = (Point::new(2.0, 0.0), Point::new(0.0, 0.5));
+
+        assert_eq!(x.angle_between(y), FRAC_PI_2);
+        assert_eq!(y.angle_between(x), FRAC_PI_2);
+        assert_eq!(x.angle_between(-x), PI);
+        assert_eq!(x.angle_between(x * 3.0), 0.0);
+
+        assert_eq!(x.signed_angle(y), FRAC_PI_2);
+        // a clockwise turn is negative
+        assert_eq!(y.signed_angle(x), -FRAC_PI_2);
+        assert!((Point::new(1.0, 1.0).signed_angle(x) + FRAC_PI_4).abs() < 1e-12);
+        // opposite vectors are a half turn either way, reported as positive
+        assert_eq!(x.signed_angle(-x), PI);
+        assert_eq!(
+            Point::new(-1.0, 0.0).signed_angle(Point::new(1.0, -0.0)),
+            PI
+        );
+    }
+
+    #[test]
+    fn cross() {
+        let (x, y) = (Point::new(1.0, 0.0), Point::new(0.0, 1.0));
+        assert_eq!(x.cross(y), 1.0);
+        assert_eq!(y.cross(x), -1.0);
+        assert_eq!(Point::unit(0.3).cross(Point::unit(0.3).perp()), 1.0);
+
+        // parallel and antiparallel vectors
+        let p = Point::new(1.5, -2.0);
+        assert_eq!(p.cross(p * 3.0), 0.0);
+        assert_eq!(p.cross(-p), 0.0);
+        assert_eq!(p.cross(Point::origin()), 0.0);
+
+        // the cross product and dot product agree on the angle between
+        let q = Point::new(-0.5, 4.0);
+        assert!((p.cross(q) - p.norm() * q.norm() * p.signed_angle(q).sin()).abs() < 1e-12);
+        assert!((p.dot(q) - p.norm() * q.norm() * p.angle_between(q).cos()).abs() < 1e-12);
+    }
+
+    #[test]
+    fn constants() {
+        assert_eq!(Point::default(), Point::origin());
+        assert_eq!(Point::ZERO, Point::origin());
+        assert_eq!(Point::X.norm(), 1.0);
+        assert_eq!(Point::Y.norm(), 1.0);
+        assert_eq!(Point::X.dot(Point::Y), 0.0);
+        assert_eq!(Point::X.perp(), Point::Y);
+    }
+}
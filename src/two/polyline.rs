@@ -0,0 +1,160 @@
+use super::boundary::Edge;
+use super::dist::Dist;
+use super::{Boundary, Point};
+
+// an open 2d chain of edges
+#[derive(Clone, Debug)]
+pub struct Polyline {
+    edges: Vec<Edge>,
+}
+
+// open chains of edges are often called paths (toolpaths, sketches)
+pub type Path = Polyline;
+
+impl Polyline {
+    pub fn new<T, U>(edges: T) -> Self
+    where
+        T: IntoIterator<Item = U>,
+        U: Into<Edge>,
+    {
+        let edges: Vec<_> = edges.into_iter().map(|e| e.into()).collect();
+
+        assert!(!edges.is_empty());
+
+        // check the nodes all agree
+        // TODO replace with some tolerance interface
+        assert!(edges
+            .iter()
+            .zip(edges.iter().skip(1))
+            .all(|(e1, e2)| e1.q().dist(e2.p()) < 1e-6));
+
+        Self { edges }
+    }
+
+    pub fn p(&self) -> Point {
+        self.edges.first().unwrap().p()
+    }
+
+    pub fn q(&self) -> Point {
+        self.edges.last().unwrap().q()
+    }
+
+    pub fn points(&self) -> Vec<Point> {
+        // every node, including both ends
+        let mut res: Vec<Point> = self.edges.iter().map(|e| e.p()).collect();
+        res.push(self.q());
+        res
+    }
+
+    pub fn num_edges(&self) -> usize {
+        self.edges.len()
+    }
+
+    pub fn edges<'a>(&'a self) -> impl Iterator<Item = &'a Edge> + 'a {
+        self.edges.iter()
+    }
+
+    pub fn length(&self) -> f64 {
+        self.edges.iter().map(|e| e.length()).sum()
+    }
+
+    pub fn point_at(&self, t: f64) -> Point {
+        // the point a fraction t of the way along the whole chain, by arc length
+        // t is clamped to [0, 1]
+        let mut s = t.clamp(0.0, 1.0) * self.length();
+        for e in self.edges.iter() {
+            let len = e.length();
+            if s <= len {
+                return e.point_at_length(s);
+            }
+            s -= len;
+        }
+        self.q()
+    }
+
+    pub fn sample(&self, n: usize) -> Vec<Point> {
+        // n points evenly spaced by arc length, including both ends
+        assert!(n >= 2);
+        (0..n)
+            .map(|i| self.point_at(i as f64 / (n - 1) as f64))
+            .collect()
+    }
+
+    pub fn try_close(&self) -> Option<Boundary> {
+        // the closed boundary along the chain, if its ends meet
+        // TODO replace with some tolerance interface
+        if self.p().dist(self.q()) < 1e-6 {
+            Some(Boundary::new(self.edges.clone()))
+        } else {
+            None
+        }
+    }
+
+    pub fn reverse(&mut self) {
+        let rev_edges = self.edges.iter().rev().map(|e| e.reverse()).collect();
+        self.edges = rev_edges;
+    }
+}
+
+impl Dist for Polyline {
+    fn dist(&self, r: Point) -> f64 {
+        self.edges()
+            .map(|e| e.dist(r))
+            .min_by(|x, y| x.partial_cmp(y).unwrap())
+            .unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::two::Segment;
+
+    fn l_path() -> Path {
+        Path::new(vec![
+            Segment::new((0.0, 2.0).into(), (0.0, 0.0).into()),
+            Segment::new((0.0, 0.0).into(), (3.0, 0.0).into()),
+        ])
+    }
+
+    #[test]
+    fn path_sample() {
+        let path = l_path();
+        assert_eq!(path.length(), 5.0);
+        assert!(path.point_at(0.2).eps_eq((0.0, 1.0).into(), 1e-12));
+        assert!(path.point_at(0.6).eps_eq((1.0, 0.0).into(), 1e-12));
+        assert_eq!(path.point_at(2.0), path.q());
+
+        let samples = path.sample(6);
+        let expected = [
+            (0.0, 2.0),
+            (0.0, 1.0),
+            (0.0, 0.0),
+            (1.0, 0.0),
+            (2.0, 0.0),
+            (3.0, 0.0),
+        ];
+        assert_eq!(samples.len(), expected.len());
+        for (p, e) in samples.iter().zip(expected) {
+            assert!(p.eps_eq(e.into(), 1e-12));
+        }
+
+        let mut rev = path.clone();
+        rev.reverse();
+        assert!(rev.point_at(0.2).eps_eq((2.0, 0.0).into(), 1e-12));
+    }
+
+    #[test]
+    fn path_close() {
+        assert!(l_path().try_close().is_none());
+
+        let nearly = Path::new(vec![
+            Segment::new((0.0, 0.0).into(), (1.0, 0.0).into()),
+            Segment::new((1.0, 0.0).into(), (0.0, 1.0).into()),
+            Segment::new((0.0, 1.0).into(), (0.0, 1e-8).into()),
+        ]);
+        let closed = nearly.try_close().unwrap();
+        assert_eq!(closed.num_edges(), 3);
+        assert!((closed.area() - 0.5).abs() < 1e-6);
+    }
+}
@@ -0,0 +1,239 @@
+// robust geometric predicates, after shewchuk's adaptive precision predicates
+// each first tries the plain floating point determinant, and only when it is too close to
+// zero for its sign to be trusted is the determinant evaluated exactly
+// the exact step uses floating point expansions: sums of doubles kept nonoverlapping and in
+// increasing magnitude, so the sign of the sum is the sign of the largest term
+
+use super::Point;
+use crate::Orient;
+
+const EPSILON: f64 = f64::EPSILON / 2.0;
+const CCW_ERRBOUND: f64 = (3.0 + 16.0 * EPSILON) * EPSILON;
+const ICC_ERRBOUND: f64 = (10.0 + 96.0 * EPSILON) * EPSILON;
+
+fn two_sum(a: f64, b: f64) -> (f64, f64) {
+    // a + b as a rounded sum and its exact error
+    let x = a + b;
+    let b_virtual = x - a;
+    let a_virtual = x - b_virtual;
+    (x, (a - a_virtual) + (b - b_virtual))
+}
+
+fn two_product(a: f64, b: f64) -> (f64, f64) {
+    // a * b as a rounded product and its exact error, via a fused multiply-add
+    let x = a * b;
+    (x, a.mul_add(b, -x))
+}
+
+#[derive(Clone, Debug, Default)]
+struct Expansion(Vec<f64>);
+
+impl Expansion {
+    fn from_diff(a: f64, b: f64) -> Self {
+        let mut e = Expansion::default();
+        e.grow(a);
+        e.grow(-b);
+        e
+    }
+
+    fn grow(&mut self, b: f64) {
+        // add a single double, exactly, dropping zero terms
+        let mut q = b;
+        let mut res = Vec::with_capacity(self.0.len() + 1);
+        for &x in self.0.iter() {
+            let (sum, err) = two_sum(q, x);
+            if err != 0.0 {
+                res.push(err);
+            }
+            q = sum;
+        }
+        if q != 0.0 {
+            res.push(q);
+        }
+        self.0 = res;
+    }
+
+    fn add(&self, other: &Expansion) -> Expansion {
+        let mut res = self.clone();
+        for &x in other.0.iter() {
+            res.grow(x);
+        }
+        res
+    }
+
+    fn sub(&self, other: &Expansion) -> Expansion {
+        let mut res = self.clone();
+        for &x in other.0.iter() {
+            res.grow(-x);
+        }
+        res
+    }
+
+    fn mul(&self, other: &Expansion) -> Expansion {
+        let mut res = Expansion::default();
+        for &x in self.0.iter() {
+            for &y in other.0.iter() {
+                let (product, err) = two_product(x, y);
+                res.grow(err);
+                res.grow(product);
+            }
+        }
+        res
+    }
+
+    fn sign(&self) -> Orient {
+        match self.0.last() {
+            Some(x) if *x > 0.0 => Orient::Positive,
+            Some(x) if *x < 0.0 => Orient::Negative,
+            _ => Orient::Zero,
+        }
+    }
+}
+
+fn sign_of(x: f64) -> Orient {
+    if x > 0.0 {
+        Orient::Positive
+    } else if x < 0.0 {
+        Orient::Negative
+    } else {
+        Orient::Zero
+    }
+}
+
+pub fn orient2d(a: Point, b: Point, c: Point) -> Orient {
+    // exact orientation of the turn a -> b -> c, positive for counterclockwise
+    let det_left = (a.x - c.x) * (b.y - c.y);
+    let det_right = (a.y - c.y) * (b.x - c.x);
+    let det = det_left - det_right;
+
+    let bound = CCW_ERRBOUND * (det_left.abs() + det_right.abs());
+    if det.abs() > bound {
+        return sign_of(det);
+    }
+
+    let acx = Expansion::from_diff(a.x, c.x);
+    let acy = Expansion::from_diff(a.y, c.y);
+    let bcx = Expansion::from_diff(b.x, c.x);
+    let bcy = Expansion::from_diff(b.y, c.y);
+    acx.mul(&bcy).sub(&acy.mul(&bcx)).sign()
+}
+
+pub fn incircle(a: Point, b: Point, c: Point, d: Point) -> Orient {
+    // exact position of d against the circle through a, b and c
+    // for counterclockwise a, b, c: positive inside, negative outside, zero on the circle
+    // the sign flips if a, b, c are clockwise
+    let (adx, ady) = (a.x - d.x, a.y - d.y);
+    let (bdx, bdy) = (b.x - d.x, b.y - d.y);
+    let (cdx, cdy) = (c.x - d.x, c.y - d.y);
+
+    let (bdxcdy, cdxbdy) = (bdx * cdy, cdx * bdy);
+    let (cdxady, adxcdy) = (cdx * ady, adx * cdy);
+    let (adxbdy, bdxady) = (adx * bdy, bdx * ady);
+    let alift = adx * adx + ady * ady;
+    let blift = bdx * bdx + bdy * bdy;
+    let clift = cdx * cdx + cdy * cdy;
+
+    let det = alift * (bdxcdy - cdxbdy) + blift * (cdxady - adxcdy) + clift * (adxbdy - bdxady);
+    let permanent = (bdxcdy.abs() + cdxbdy.abs()) * alift
+        + (cdxady.abs() + adxcdy.abs()) * blift
+        + (adxbdy.abs() + bdxady.abs()) * clift;
+
+    let bound = ICC_ERRBOUND * permanent;
+    if det.abs() > bound {
+        return sign_of(det);
+    }
+
+    let (adx, ady) = (
+        Expansion::from_diff(a.x, d.x),
+        Expansion::from_diff(a.y, d.y),
+    );
+    let (bdx, bdy) = (
+        Expansion::from_diff(b.x, d.x),
+        Expansion::from_diff(b.y, d.y),
+    );
+    let (cdx, cdy) = (
+        Expansion::from_diff(c.x, d.x),
+        Expansion::from_diff(c.y, d.y),
+    );
+
+    let lift = |x: &Expansion, y: &Expansion| x.mul(x).add(&y.mul(y));
+    let cross = |x1: &Expansion, y1: &Expansion, x2: &Expansion, y2: &Expansion| {
+        x1.mul(y2).sub(&x2.mul(y1))
+    };
+
+    let a_term = lift(&adx, &ady).mul(&cross(&bdx, &bdy, &cdx, &cdy));
+    let b_term = lift(&bdx, &bdy).mul(&cross(&cdx, &cdy, &adx, &ady));
+    let c_term = lift(&cdx, &cdy).mul(&cross(&adx, &ady, &bdx, &bdy));
+    a_term.add(&b_term).add(&c_term).sign()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn step(x: f64, ulps: i64) -> f64 {
+        // the double ulps representable steps away from positive x
+        f64::from_bits((x.to_bits() as i64 + ulps) as u64)
+    }
+
+    #[test]
+    fn orient_near_colinear() {
+        // q and r lie exactly on y = x, and p is nudged off it by a few ulps, so the true
+        // orientation is the sign of p.y - p.x
+        // the naive determinant pivoting on p gets many of these wrong
+        let (q, r) = (Point::new(12.0, 12.0), Point::new(24.0, 24.0));
+
+        let mut naive_wrong = 0;
+        for i in 0..32 {
+            for j in 0..32 {
+                let p = Point::new(step(0.5, i), step(0.5, j));
+                let exact = sign_of(p.y - p.x);
+                assert_eq!(orient2d(p, q, r), exact);
+                assert_eq!(orient2d(q, r, p), exact);
+                assert_eq!(orient2d(p, r, q), exact.reverse());
+
+                let naive = (q.x - p.x) * (r.y - p.y) - (q.y - p.y) * (r.x - p.x);
+                if sign_of(naive) != exact {
+                    naive_wrong += 1;
+                }
+            }
+        }
+        assert!(naive_wrong > 0);
+    }
+
+    #[test]
+    fn incircle_near_cocircular() {
+        // a, b, c and the bottom point of their circle are exactly cocircular
+        // d is nudged from that point by a few ulps: up is inside, down is outside, and
+        // sideways is outside by only a second order amount
+        let (x0, y0, u) = (1000.1, 2000.3, 1.0 / 1024.0);
+        let (a, b, c) = (
+            Point::new(x0 + u, y0),
+            Point::new(x0, y0 + u),
+            Point::new(x0 - u, y0),
+        );
+
+        let mut naive_wrong = 0;
+        for i in -3..=3 {
+            for j in -3..=3 {
+                let d = Point::new(step(x0, i), step(y0 - u, j));
+                let exact = match (i, j) {
+                    (_, j) if j > 0 => Orient::Positive,
+                    (0, 0) => Orient::Zero,
+                    _ => Orient::Negative,
+                };
+                assert_eq!(incircle(a, b, c, d), exact);
+                assert_eq!(incircle(b, a, c, d), exact.reverse());
+
+                let lift = |p: Point| (p.x - d.x).powi(2) + (p.y - d.y).powi(2);
+                let cross =
+                    |p: Point, q: Point| (p.x - d.x) * (q.y - d.y) - (q.x - d.x) * (p.y - d.y);
+                let naive = lift(a) * cross(b, c) + lift(b) * cross(c, a) + lift(c) * cross(a, b);
+                if sign_of(naive) != exact {
+                    naive_wrong += 1;
+                }
+            }
+        }
+        assert!(naive_wrong > 0);
+    }
+}
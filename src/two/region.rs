@@ -0,0 +1,158 @@
+// regions bounded by an outer boundary, less any holes cut out of it
+
+use std::fmt;
+
+use super::dist::Dist;
+use super::{Boundary, Point};
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum RegionError {
+    // the hole at this index crosses or lies outside the outer boundary
+    HoleOutside(usize),
+    // the holes at these indices cross, or one lies inside the other
+    HolesOverlap(usize, usize),
+}
+
+impl fmt::Display for RegionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RegionError::HoleOutside(i) => write!(f, "hole {} is not inside the outer boundary", i),
+            RegionError::HolesOverlap(i, j) => write!(f, "holes {} and {} overlap", i, j),
+        }
+    }
+}
+
+impl std::error::Error for RegionError {}
+
+// the outer boundary is kept counterclockwise and the holes clockwise, so the signed areas of
+// all the loops sum to the area of the region
+#[derive(Clone, Debug)]
+pub struct Region {
+    outer: Boundary,
+    holes: Vec<Boundary>,
+}
+
+impl Region {
+    pub fn new(mut outer: Boundary, mut holes: Vec<Boundary>) -> Result<Self, RegionError> {
+        // holes must lie strictly inside the outer boundary, and apart from each other
+        for (i, hole) in holes.iter().enumerate() {
+            if outer.contains_boundary(hole) != Ok(true) {
+                return Err(RegionError::HoleOutside(i));
+            }
+            for (j, other) in holes.iter().enumerate().skip(i + 1) {
+                let apart = hole.contains_boundary(other) == Ok(false)
+                    && other.contains_boundary(hole) == Ok(false);
+                if !apart {
+                    return Err(RegionError::HolesOverlap(i, j));
+                }
+            }
+        }
+
+        outer.orient_positive();
+        for hole in holes.iter_mut() {
+            hole.orient_negative();
+        }
+        Ok(Region { outer, holes })
+    }
+
+    pub fn outer(&self) -> &Boundary {
+        &self.outer
+    }
+
+    pub fn holes(&self) -> &[Boundary] {
+        &self.holes
+    }
+
+    pub fn boundaries(&self) -> impl Iterator<Item = &Boundary> {
+        // the outer boundary, then each hole
+        std::iter::once(&self.outer).chain(self.holes.iter())
+    }
+
+    pub fn area(&self) -> f64 {
+        // the outer area less the holes', always positive
+        self.boundaries().map(|b| b.area()).sum()
+    }
+
+    pub fn contains(&self, x: Point) -> bool {
+        // inside the outer boundary and outside every hole
+        self.outer.contains(x) && !self.holes.iter().any(|h| h.contains(x))
+    }
+}
+
+impl Dist for Region {
+    fn dist(&self, r: Point) -> f64 {
+        // distance to the nearest boundary, inside or out
+        self.boundaries()
+            .map(|b| b.dist(r))
+            .fold(f64::INFINITY, f64::min)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::two::Arc;
+
+    fn square(min: f64, max: f64) -> Boundary {
+        Boundary::from_points(&[
+            (min, min).into(),
+            (max, min).into(),
+            (max, max).into(),
+            (min, max).into(),
+        ])
+    }
+
+    fn circle(center: Point, r: f64) -> Boundary {
+        Boundary::new(vec![Arc::from_center_ang(center, r, 0.0, 0.0, true)])
+    }
+
+    #[test]
+    fn square_with_hole() {
+        let region = Region::new(square(0.0, 4.0), vec![circle((2.0, 2.0).into(), 1.0)]).unwrap();
+
+        let expected = 16.0 - std::f64::consts::PI;
+        assert!((region.area() - expected).abs() < 1e-9);
+
+        assert!(region.contains((0.5, 0.5).into()));
+        assert!(!region.contains((2.0, 2.0).into()));
+        assert!(!region.contains((2.5, 2.2).into()));
+        assert!(!region.contains((5.0, 2.0).into()));
+
+        // nearest boundary is the hole from its center, and the outer square near the corner
+        assert!((region.dist((2.0, 2.0).into()) - 1.0).abs() < 1e-9);
+        assert!((region.dist((0.25, 0.5).into()) - 0.25).abs() < 1e-9);
+        assert!((region.dist((2.0, 3.5).into()) - 0.5).abs() < 1e-9);
+    }
+
+    #[test]
+    fn orientation() {
+        // holes given either way round are stored clockwise
+        let mut outer = square(0.0, 4.0);
+        outer.reverse();
+        let region = Region::new(outer, vec![square(1.0, 2.0), square(2.5, 3.5)]).unwrap();
+        assert!(region.outer().area() > 0.0);
+        assert!(region.holes().iter().all(|h| h.area() < 0.0));
+        assert!((region.area() - 14.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn invalid_holes() {
+        let outside = Region::new(square(0.0, 4.0), vec![square(5.0, 6.0)]);
+        assert_eq!(outside.unwrap_err(), RegionError::HoleOutside(0));
+
+        let crossing = Region::new(square(0.0, 4.0), vec![square(3.0, 5.0)]);
+        assert_eq!(crossing.unwrap_err(), RegionError::HoleOutside(0));
+
+        let overlapping = Region::new(
+            square(0.0, 4.0),
+            vec![square(0.5, 0.9), square(1.0, 2.0), square(1.5, 2.5)],
+        );
+        assert_eq!(overlapping.unwrap_err(), RegionError::HolesOverlap(1, 2));
+
+        let nested = Region::new(
+            square(0.0, 4.0),
+            vec![square(1.0, 3.0), circle((2.0, 2.0).into(), 0.5)],
+        );
+        assert_eq!(nested.unwrap_err(), RegionError::HolesOverlap(0, 1));
+    }
+}
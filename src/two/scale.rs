@@ -0,0 +1,111 @@
+use super::boundary::Edge;
+use super::{Arc, Boundary, Circle, Point, Segment};
+
+// uniform scaling about a center point
+// a negative factor also turns everything a half turn about the center
+// non-uniform scaling is not provided, as it would turn circles and arcs into ellipses
+pub trait Scale {
+    fn scale(&self, center: Point, factor: f64) -> Self;
+}
+
+impl Scale for Point {
+    fn scale(&self, center: Point, factor: f64) -> Point {
+        center + (*self - center) * factor
+    }
+}
+
+impl Scale for Segment {
+    fn scale(&self, center: Point, factor: f64) -> Segment {
+        let (p, q) = self.into_points();
+        Segment::new(p.scale(center, factor), q.scale(center, factor))
+    }
+}
+
+impl Scale for Circle {
+    fn scale(&self, center: Point, factor: f64) -> Circle {
+        Circle::new(
+            self.center.scale(center, factor),
+            self.radius * factor.abs(),
+        )
+    }
+}
+
+impl Scale for Arc {
+    fn scale(&self, center: Point, factor: f64) -> Arc {
+        // angles are kept, unless a negative factor turns them a half turn
+        let turn = |ang: f64| {
+            if factor < 0.0 {
+                (ang + std::f64::consts::PI).rem_euclid(std::f64::consts::TAU)
+            } else {
+                ang
+            }
+        };
+        Arc::from_center_ang(
+            self.center().scale(center, factor),
+            self.radius() * factor.abs(),
+            turn(self.p_ang()),
+            turn(self.q_ang()),
+            self.ccw(),
+        )
+    }
+}
+
+impl Scale for Edge {
+    fn scale(&self, center: Point, factor: f64) -> Edge {
+        match self {
+            Edge::Segment(s) => Edge::Segment(s.scale(center, factor)),
+            Edge::Arc(a) => Edge::Arc(a.scale(center, factor)),
+            Edge::Bezier(b) => Edge::Bezier(b.map_controls(|p| p.scale(center, factor))),
+        }
+    }
+}
+
+impl Scale for Boundary {
+    fn scale(&self, center: Point, factor: f64) -> Boundary {
+        Boundary::new(self.edges().map(|e| e.scale(center, factor)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scale_circle() {
+        let circle = Circle::new((1.0, 2.0).into(), 1.5);
+
+        // about its own center only the radius changes
+        let about_center = circle.scale(circle.center, 2.0);
+        assert_eq!(about_center.center, circle.center);
+        assert_eq!(about_center.radius, 3.0);
+
+        // about another point the center moves away from it
+        let about_origin = circle.scale(Point::origin(), 2.0);
+        assert!(about_origin.center.eps_eq((2.0, 4.0).into(), 1e-12));
+        assert_eq!(about_origin.radius, 3.0);
+
+        let flipped = circle.scale((0.0, 1.0).into(), -0.5);
+        assert!(flipped.center.eps_eq((-0.5, 0.5).into(), 1e-12));
+        assert_eq!(flipped.radius, 0.75);
+    }
+
+    #[test]
+    fn scale_boundary() {
+        let bound = Boundary::from_bulge_polyline(&[
+            ((0.0, 0.0).into(), 0.0),
+            ((2.0, 0.0).into(), 0.5),
+            ((2.0, 1.0).into(), 0.0),
+            ((0.0, 1.0).into(), 0.0),
+        ]);
+        let center = Point::new(3.0, -1.0);
+
+        for factor in [0.5, 3.0, -2.0] {
+            let scaled = bound.scale(center, factor);
+            assert!((scaled.area() - bound.area() * factor * factor).abs() < 1e-9);
+            for (e, s) in bound.edges().zip(scaled.edges()) {
+                assert!(s.p().eps_eq(e.p().scale(center, factor), 1e-9));
+                assert!(s.mid().eps_eq(e.mid().scale(center, factor), 1e-9));
+            }
+        }
+    }
+}
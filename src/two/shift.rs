@@ -1,4 +1,4 @@
-use super::{Arc, Circle, Line, Point, Ray, Segment};
+use super::{Arc, Circle, Line, Point, Ray, Segment, Vector};
 
 pub trait Shift {
     fn shift(&self, r: Point) -> Self;
@@ -7,7 +7,7 @@ pub trait Shift {
     where
         Self: Sized,
     {
-        self.shift(r * -1.0)
+        self.shift(-r)
     }
 }
 
@@ -17,7 +17,14 @@ impl Shift for Point {
     }
 
     fn shift_subtract(&self, r: Point) -> Point {
-        self.clone() - r
+        self.clone() + -r
+    }
+}
+
+impl Shift for Vector {
+    // a vector has no position, so it is unchanged
+    fn shift(&self, _r: Point) -> Vector {
+        *self
     }
 }
 
@@ -0,0 +1,109 @@
+use std::fmt::Write;
+
+use super::boundary::{Boundary, Edge};
+use super::dist::Dist;
+use super::{Arc, Point};
+
+fn write_point(d: &mut String, p: Point) {
+    write!(d, "{} {}", p.x, p.y).unwrap();
+}
+
+fn write_arc(d: &mut String, a: &Arc) {
+    // elliptical arc command: A rx ry rotation large-arc sweep x y
+    let (p_ang, q_ang) = a.pq_ang_unbounded();
+    let sweep_ang = (q_ang - p_ang).abs();
+    let sweep = if a.ccw() { 1 } else { 0 };
+
+    if a.p().dist(a.q()) < 1e-9 {
+        // svg drops arcs whose endpoints coincide, so draw a full circle as two halves
+        let half = a.to_circle().at_ang((p_ang + q_ang) / 2.0);
+        write!(d, " A {} {} 0 0 {} ", a.radius(), a.radius(), sweep).unwrap();
+        write_point(d, half);
+        write!(d, " A {} {} 0 0 {} ", a.radius(), a.radius(), sweep).unwrap();
+        write_point(d, a.q());
+    } else {
+        let large = if sweep_ang > std::f64::consts::PI {
+            1
+        } else {
+            0
+        };
+        write!(d, " A {} {} 0 {} {} ", a.radius(), a.radius(), large, sweep).unwrap();
+        write_point(d, a.q());
+    }
+}
+
+impl Boundary {
+    pub fn to_svg_path(&self) -> String {
+        // svg path data (the `d` attribute), closed with Z
+        // the final segment is implied by the closing command
+        let mut d = String::from("M ");
+        write_point(&mut d, self.points()[0]);
+
+        let n = self.num_edges();
+        for (i, e) in self.edges().enumerate() {
+            match e {
+                Edge::Segment(s) => {
+                    if i + 1 < n {
+                        d.push_str(" L ");
+                        write_point(&mut d, s.q());
+                    }
+                }
+                Edge::Arc(a) => write_arc(&mut d, a),
+                Edge::Bezier(b) => {
+                    // cubic command: C x1 y1 x2 y2 x y
+                    d.push_str(" C ");
+                    write_point(&mut d, b.1);
+                    d.push(' ');
+                    write_point(&mut d, b.2);
+                    d.push(' ');
+                    write_point(&mut d, b.3);
+                }
+            }
+        }
+
+        d.push_str(" Z");
+        d
+    }
+
+    pub fn to_svg_document(&self, width: f64, height: f64) -> String {
+        // minimal standalone svg, drawing the boundary as an unfilled outline
+        format!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{}\" height=\"{}\">\
+             <path d=\"{}\" fill=\"none\" stroke=\"black\"/></svg>",
+            width,
+            height,
+            self.to_svg_path()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::line::{Arc, Segment};
+    use super::Boundary;
+
+    #[test]
+    fn square_path() {
+        let edges = vec![
+            Segment::new((0.0, 0.0).into(), (1.0, 0.0).into()),
+            Segment::new((1.0, 0.0).into(), (1.0, 1.0).into()),
+            Segment::new((1.0, 1.0).into(), (0.0, 1.0).into()),
+            Segment::new((0.0, 1.0).into(), (0.0, 0.0).into()),
+        ];
+
+        let bound = Boundary::new(edges);
+        assert_eq!(bound.to_svg_path(), "M 0 0 L 1 0 L 1 1 L 0 1 Z");
+    }
+
+    #[test]
+    fn circle_path() {
+        let edges = vec![Arc::from_center_ang((0.0, 0.0).into(), 2.0, 0.0, 0.0, true)];
+
+        let bound = Boundary::new(edges);
+        let d = bound.to_svg_path();
+
+        // a full circle needs two arc commands
+        assert_eq!(d.matches('A').count(), 2);
+        assert!(bound.to_svg_document(10.0, 10.0).contains(&d));
+    }
+}
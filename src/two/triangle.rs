@@ -1,23 +1,37 @@
+use super::aabb::Aabb;
 use super::dist::Dist;
-use super::Point;
+use super::predicates::{incircle, orient2d};
+use super::{Point, Vector};
 use crate::Orient;
 
+pub fn orient3(a: Point, b: Point, c: Point, tol: f64) -> Orient {
+    // orientation of the turn a -> b -> c
+    // positive is natural (ccw), negative is cw, zero is colinear
+    // tol bounds the cross product (b - a) x (c - a), i.e. twice the signed area
+    let val = (b - a).cross(c - a);
+    if val > tol {
+        Orient::Positive
+    } else if val < -tol {
+        Orient::Negative
+    } else {
+        Orient::Zero
+    }
+}
+
+pub fn orient(p: Point, q: Point, r: Point) -> Orient {
+    // exact orientation, so only exactly colinear points are Zero, see predicates
+    orient2d(p, q, r)
+}
+
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub struct Triangle(Point, Point, Point);
 
 impl Triangle {
     pub fn dir(self) -> Orient {
         // return whether the triangle (p, q, r) turns counterclockwise
-        // postive is natural (ccw), negative is cw
+        // positive is natural (ccw), negative is cw, see orient
         let Triangle(p, q, r) = self;
-        let val = (q.y - p.y) * (r.x - q.x) - (q.x - p.x) * (r.y - q.y);
-        if val == 0.0 {
-            Orient::Zero
-        } else if val > 0.0 {
-            Orient::Negative
-        } else {
-            Orient::Positive
-        }
+        orient(p, q, r)
     }
 
     pub fn into_points(self) -> (Point, Point, Point) {
@@ -46,6 +60,27 @@ impl Triangle {
         rng.contains(&a) && rng.contains(&b) && rng.contains(&c)
     }
 
+    pub fn rasterize(&self, resolution: f64) -> Vec<Point> {
+        // centers of the grid cells (of side resolution, aligned to the origin) inside the triangle
+        let Aabb { min, max } = Aabb::from_points([self.0, self.1, self.2]);
+
+        let (i_min, i_max) = ((min.x / resolution).floor(), (max.x / resolution).ceil());
+        let (j_min, j_max) = ((min.y / resolution).floor(), (max.y / resolution).ceil());
+
+        let mut res = Vec::new();
+        for i in (i_min as i64)..(i_max as i64) {
+            for j in (j_min as i64)..(j_max as i64) {
+                let center =
+                    Point::new((i as f64 + 0.5) * resolution, (j as f64 + 0.5) * resolution);
+                if self.in_triangle(center) {
+                    res.push(center);
+                }
+            }
+        }
+
+        res
+    }
+
     pub fn circumradius(self) -> f64 {
         let a = self.0.dist(self.1);
         let b = self.1.dist(self.2);
@@ -53,6 +88,22 @@ impl Triangle {
 
         (a * b * c) / ((a + b + c) * (b + c - a) * (c + a - b) * (a + b - c)).sqrt()
     }
+
+    pub fn circumcenter(self) -> Point {
+        // center of the circle through all three corners, not finite for colinear corners
+        let Triangle(p, q, r) = self;
+        let (b, c) = (q - p, r - p);
+        let d = 2.0 * b.cross(c);
+        let (b2, c2) = (b.dot(b), c.dot(c));
+        p + Vector::new(c.y * b2 - b.y * c2, b.x * c2 - c.x * b2) / d
+    }
+
+    pub fn in_circumcircle(self, p: Point) -> bool {
+        // whether p lies strictly inside the circle through the corners, decided exactly
+        let Triangle(a, b, c) = self;
+        let dir = self.dir();
+        dir != Orient::Zero && incircle(a, b, c, p) == dir
+    }
 }
 
 impl From<(Point, Point, Point)> for Triangle {
@@ -60,3 +111,52 @@ impl From<(Point, Point, Point)> for Triangle {
         Self(tri.0, tri.1, tri.2)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn orient_convention() {
+        let (a, b, c) = ((0.0, 0.0).into(), (1.0, 0.0).into(), (0.0, 1.0).into());
+
+        assert_eq!(orient3(a, b, c, 1e-9), Orient::Positive);
+        assert_eq!(orient3(a, c, b, 1e-9), Orient::Negative);
+        assert_eq!(orient3(a, b, (2.0, 1e-12).into(), 1e-9), Orient::Zero);
+
+        assert_eq!(Triangle(a, b, c).dir(), Orient::Positive);
+        assert_eq!(Triangle(a, c, b).dir(), Orient::Negative);
+    }
+
+    #[test]
+    fn orient_triples() {
+        let (a, b, c) = ((0.0, 0.0).into(), (2.0, 0.0).into(), (1.0, 1.0).into());
+        assert_eq!(orient(a, b, c), Orient::Positive);
+        assert_eq!(orient(a, c, b), Orient::Negative);
+        assert_eq!(orient(a, c, b), orient(a, b, c).reverse());
+        assert_eq!(orient(a, b, (5.0, 0.0).into()), Orient::Zero);
+        assert_eq!(orient(a, c, (-3.0, -3.0).into()), Orient::Zero);
+    }
+
+    #[test]
+    fn rasterize_right_triangle() {
+        let tri = Triangle((0.0, 0.0).into(), (10.0, 0.0).into(), (0.0, 10.0).into());
+        let resolution = 0.1;
+        let cells = tri.rasterize(resolution);
+
+        let covered = cells.len() as f64 * resolution.powi(2);
+        assert!((covered - 50.0).abs() < 1.0);
+        assert!(cells.iter().all(|p| tri.in_triangle(*p)));
+    }
+
+    #[test]
+    fn circumcircle() {
+        let tri = Triangle((0.0, 0.0).into(), (4.0, 0.0).into(), (0.0, 2.0).into());
+        let center = tri.circumcenter();
+        assert!(center.dist((2.0, 1.0).into()) < 1e-12);
+        assert!((tri.circumradius() - 5.0f64.sqrt()).abs() < 1e-12);
+
+        assert!(tri.in_circumcircle((3.0, 2.0).into()));
+        assert!(!tri.in_circumcircle((4.5, 2.0).into()));
+    }
+}
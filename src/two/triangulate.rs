@@ -0,0 +1,455 @@
+// meshing the interior of a boundary, and of free point sets
+
+use std::collections::{HashMap, HashSet};
+
+use super::triangle::orient;
+use super::{Aabb, Boundary, Point, Region, Triangle, Vector};
+use crate::Orient;
+
+fn is_ear(ring: &[Point], i: usize) -> bool {
+    // the corner at i can be cut off if it is convex and no other node lies in the cut
+    let n = ring.len();
+    let (a, b, c) = (ring[(i + n - 1) % n], ring[i], ring[(i + 1) % n]);
+    if orient(a, b, c) != Orient::Positive {
+        return false;
+    }
+
+    let tri = Triangle::from((a, b, c));
+    ring.iter()
+        .filter(|&&p| p != a && p != b && p != c)
+        .all(|&p| !tri.in_triangle(p))
+}
+
+fn corner(ring: &[Point], i: usize) -> Triangle {
+    let n = ring.len();
+    Triangle::from((ring[(i + n - 1) % n], ring[i], ring[(i + 1) % n]))
+}
+
+impl Boundary {
+    pub fn triangulate(&self) -> Vec<Triangle> {
+        // ear clipping triangulation of a simple boundary, O(n^3) in the worst case
+        // arcs are flattened first, so the triangles only cover their chords
+        // triangles are returned counterclockwise
+        let mut ring = self.flattened_points();
+
+        let mut res = Vec::new();
+        while ring.len() > 3 {
+            let n = ring.len();
+            let turn = |i: usize| corner(&ring, i).dir();
+
+            let (i, keep) = if let Some(i) = (0..n).find(|&i| is_ear(&ring, i)) {
+                (i, true)
+            } else if let Some(i) = (0..n).find(|&i| turn(i) == Orient::Zero) {
+                // a colinear node encloses nothing, drop it
+                (i, false)
+            } else if let Some(i) = (0..n).find(|&i| turn(i) == Orient::Positive) {
+                // no clean ear, so the boundary is not simple
+                // cut any convex corner rather than giving up
+                (i, true)
+            } else {
+                break;
+            };
+
+            if keep {
+                res.push(corner(&ring, i));
+            }
+            ring.remove(i);
+        }
+
+        if ring.len() == 3 && corner(&ring, 1).dir() == Orient::Positive {
+            res.push(corner(&ring, 1));
+        }
+
+        res
+    }
+}
+
+pub fn delaunay(points: &[Point]) -> Vec<Triangle> {
+    // bowyer-watson: insert the points one at a time into a triangulation of a
+    // super-triangle enclosing them all, re-triangulating the cavity of triangles whose
+    // circumcircles contain the new point
+    // triangles are returned counterclockwise, O(n^2) in the worst case
+    if points.len() < 3 {
+        return Vec::new();
+    }
+
+    let mut mesh = Mesh::new(&Aabb::from_points(points.iter().copied()));
+    for &p in points {
+        mesh.insert(p);
+    }
+    mesh.triangles()
+        .map(|t| Triangle::from((points[t[0]], points[t[1]], points[t[2]])))
+        .collect()
+}
+
+// a delaunay triangulation built up one point at a time, see delaunay
+// vertices 0, 1 and 2 are the corners of the super-triangle, and are hidden from callers,
+// so point i of the mesh is vertex i + 3
+struct Mesh {
+    verts: Vec<Point>,
+    tris: Vec<[usize; 3]>,
+    // slots in tris freed by removed triangles, for reuse
+    free: Vec<usize>,
+    // each directed edge of a ccw triangle, to the triangle it bounds
+    edges: HashMap<(usize, usize), usize>,
+    // the latest triangle made, where the walk to the next point starts
+    last: usize,
+}
+
+impl Mesh {
+    fn new(bbox: &Aabb) -> Self {
+        // an empty mesh, ready for points in bbox
+        let c = bbox.center();
+        let d = bbox.width().max(bbox.height()).max(1.0);
+        let mut mesh = Mesh {
+            verts: vec![
+                c + Vector::new(-20.0 * d, -d),
+                c + Vector::new(20.0 * d, -d),
+                c + Vector::new(0.0, 20.0 * d),
+            ],
+            tris: Vec::new(),
+            free: Vec::new(),
+            edges: HashMap::new(),
+            last: 0,
+        };
+        mesh.add([0, 1, 2]);
+        mesh
+    }
+
+    fn point(&self, i: usize) -> Point {
+        self.verts[i + 3]
+    }
+
+    fn triangle(&self, t: usize) -> Triangle {
+        let [a, b, c] = self.tris[t];
+        Triangle::from((self.verts[a], self.verts[b], self.verts[c]))
+    }
+
+    fn sides(t: [usize; 3]) -> [(usize, usize); 3] {
+        [(t[0], t[1]), (t[1], t[2]), (t[2], t[0])]
+    }
+
+    fn add(&mut self, t: [usize; 3]) {
+        let slot = match self.free.pop() {
+            Some(slot) => {
+                self.tris[slot] = t;
+                slot
+            }
+            None => {
+                self.tris.push(t);
+                self.tris.len() - 1
+            }
+        };
+        for side in Self::sides(t) {
+            self.edges.insert(side, slot);
+        }
+        self.last = slot;
+    }
+
+    fn locate(&self, p: Point) -> Option<usize> {
+        // a triangle whose circumcircle holds p, walking from the last one made towards p
+        let mut t = self.last;
+        'walk: for _ in 0..self.tris.len() {
+            for (a, b) in Self::sides(self.tris[t]) {
+                if orient(self.verts[a], self.verts[b], p) == Orient::Negative {
+                    match self.edges.get(&(b, a)) {
+                        Some(&u) => {
+                            t = u;
+                            continue 'walk;
+                        }
+                        None => return None,
+                    }
+                }
+            }
+            return Some(t).filter(|&t| self.triangle(t).in_circumcircle(p));
+        }
+
+        // the walk went round in circles, so search every live triangle
+        (0..self.tris.len())
+            .filter(|t| !self.free.contains(t))
+            .find(|&t| self.triangle(t).in_circumcircle(p))
+    }
+
+    fn insert(&mut self, p: Point) -> usize {
+        // add p, returning its index, and restore the delaunay property around it
+        // a repeated point is given an index but left out of the triangles
+        let i = self.verts.len();
+        self.verts.push(p);
+        let Some(start) = self.locate(p) else {
+            return i - 3;
+        };
+
+        // the cavity: triangles whose circumcircles contain p, all connected to the first
+        let mut bad = vec![start];
+        let mut seen: HashSet<usize> = HashSet::from([start]);
+        let mut k = 0;
+        while k < bad.len() {
+            for (a, b) in Self::sides(self.tris[bad[k]]) {
+                if let Some(&u) = self.edges.get(&(b, a)) {
+                    if !seen.contains(&u) && self.triangle(u).in_circumcircle(p) {
+                        seen.insert(u);
+                        bad.push(u);
+                    }
+                }
+            }
+            k += 1;
+        }
+
+        // the cavity boundary is every edge of a bad triangle not shared with another
+        let mut rim = Vec::new();
+        for &t in bad.iter() {
+            for (a, b) in Self::sides(self.tris[t]) {
+                let shared = self.edges.get(&(b, a)).is_some_and(|u| seen.contains(u));
+                if !shared {
+                    rim.push((a, b));
+                }
+            }
+        }
+        for &t in bad.iter() {
+            for side in Self::sides(self.tris[t]) {
+                self.edges.remove(&side);
+            }
+            self.free.push(t);
+        }
+        for (a, b) in rim {
+            // the new point is left of every ccw cavity edge
+            self.add([a, b, i]);
+        }
+
+        i - 3
+    }
+
+    fn has_edge(&self, a: usize, b: usize) -> bool {
+        // whether points a and b are joined, either way round
+        self.edges.contains_key(&(a + 3, b + 3)) || self.edges.contains_key(&(b + 3, a + 3))
+    }
+
+    fn triangles(&self) -> impl Iterator<Item = [usize; 3]> + '_ {
+        // the live triangles not touching the super-triangle, by point index
+        let free: HashSet<usize> = self.free.iter().copied().collect();
+        self.tris
+            .iter()
+            .enumerate()
+            .filter(move |(t, _)| !free.contains(t))
+            .map(|(_, t)| *t)
+            .filter(|t| t.iter().all(|&v| v >= 3))
+            .map(|t| t.map(|v| v - 3))
+    }
+}
+
+impl Region {
+    pub fn triangulate(&self, max_area: Option<f64>) -> Vec<Triangle> {
+        // conforming delaunay triangulation of the region, with arcs flattened to chords
+        // chords missing from the delaunay triangulation of the boundary nodes are split at
+        // their midpoints until every piece appears, then triangles outside the region are
+        // dropped; with max_area, the centroids of larger triangles are added until none remain
+        // new points go into the existing triangulation, only re-triangulating around them
+        // triangles are returned counterclockwise
+        if let Some(max_area) = max_area {
+            assert!(max_area > 0.0);
+        }
+
+        let mut rings: Vec<Vec<Point>> = self.boundaries().map(|b| b.flattened_points()).collect();
+        for ring in rings.iter_mut() {
+            ring.dedup();
+            if ring.len() > 1 && ring.first() == ring.last() {
+                ring.pop();
+            }
+        }
+        let polys: Vec<Boundary> = rings.iter().map(|r| Boundary::from_points(r)).collect();
+        let inside = |p: Point| polys[0].contains(p) && !polys[1..].iter().any(|h| h.contains(p));
+
+        // boundary chords, as pairs of point indices in the mesh
+        let mut mesh = Mesh::new(&Aabb::from_points(rings.iter().flatten().copied()));
+        let mut chords: Vec<(usize, usize)> = Vec::new();
+        for ring in rings.iter() {
+            let indices: Vec<usize> = ring.iter().map(|&p| mesh.insert(p)).collect();
+            let n = indices.len();
+            chords.extend((0..n).map(|i| (indices[i], indices[(i + 1) % n])));
+        }
+
+        loop {
+            let (kept, missing): (Vec<_>, Vec<_>) =
+                chords.iter().partition(|&&(a, b)| mesh.has_edge(a, b));
+            if !missing.is_empty() {
+                chords = kept;
+                for (a, b) in missing {
+                    let mid = mesh.insert(mesh.point(a).lerp(mesh.point(b), 0.5));
+                    chords.push((a, mid));
+                    chords.push((mid, b));
+                }
+                continue;
+            }
+
+            let tris: Vec<Triangle> = mesh
+                .triangles()
+                .map(|[a, b, c]| (mesh.point(a), mesh.point(b), mesh.point(c)))
+                .filter(|&(a, b, c)| inside((a + b + c) / 3.0))
+                .map(Triangle::from)
+                .collect();
+
+            let large: Vec<Point> = match max_area {
+                Some(max_area) => tris
+                    .iter()
+                    .map(|t| t.into_points())
+                    .filter(|&(a, b, c)| (b - a).cross(c - a) / 2.0 > max_area)
+                    .map(|(a, b, c)| (a + b + c) / 3.0)
+                    .collect(),
+                None => Vec::new(),
+            };
+            if large.is_empty() {
+                return tris;
+            }
+            for p in large {
+                mesh.insert(p);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tri_area(t: &Triangle) -> f64 {
+        let (a, b, c) = t.into_points();
+        (b - a).cross(c - a) / 2.0
+    }
+
+    #[test]
+    fn triangulate_quad() {
+        let mut quad = Boundary::from_points(&[
+            (0.0, 0.0).into(),
+            (2.0, 0.5).into(),
+            (2.5, 2.0).into(),
+            (-0.5, 1.5).into(),
+        ]);
+        quad.reverse();
+
+        let tris = quad.triangulate();
+        assert_eq!(tris.len(), 2);
+        assert!(tris.iter().all(|t| t.dir() == Orient::Positive));
+
+        let total: f64 = tris.iter().map(tri_area).sum();
+        assert!((total - quad.area().abs()).abs() < 1e-12);
+    }
+
+    #[test]
+    fn triangulate_concave() {
+        let l_shape = Boundary::from_points(&[
+            (0.0, 0.0).into(),
+            (2.0, 0.0).into(),
+            (2.0, 1.0).into(),
+            (1.0, 1.0).into(),
+            (1.0, 2.0).into(),
+            (0.0, 2.0).into(),
+        ]);
+
+        let tris = l_shape.triangulate();
+        assert_eq!(tris.len(), 4);
+        assert!(tris.iter().all(|t| t.dir() == Orient::Positive));
+
+        let total: f64 = tris.iter().map(tri_area).sum();
+        assert!((total - l_shape.area()).abs() < 1e-12);
+
+        // nothing covers the notch
+        let notch = Point::new(1.5, 1.5);
+        assert!(tris.iter().all(|t| !t.in_triangle(notch)));
+    }
+
+    #[test]
+    fn delaunay_square() {
+        let points: Vec<Point> = vec![
+            (0.0, 0.0).into(),
+            (1.0, 0.0).into(),
+            (1.0, 1.0).into(),
+            (0.0, 1.0).into(),
+        ];
+
+        let tris = delaunay(&points);
+        assert_eq!(tris.len(), 2);
+        assert!(tris.iter().all(|t| t.dir() == Orient::Positive));
+
+        let total: f64 = tris.iter().map(tri_area).sum();
+        assert!((total - 1.0).abs() < 1e-12);
+
+        // the corners are cocircular, so either diagonal is delaunay, but both triangles
+        // must share the same one
+        let shared = points
+            .iter()
+            .filter(|p| {
+                tris.iter().all(|t| {
+                    let (a, b, c) = t.into_points();
+                    [a, b, c].contains(p)
+                })
+            })
+            .count();
+        assert_eq!(shared, 2);
+    }
+
+    #[test]
+    fn delaunay_diagonal() {
+        // a rhombus, long in x, must be split along its short diagonal
+        let points: Vec<Point> = vec![
+            (0.0, 0.0).into(),
+            (2.0, -1.0).into(),
+            (4.0, 0.0).into(),
+            (2.0, 1.0).into(),
+        ];
+
+        let tris = delaunay(&points);
+        assert_eq!(tris.len(), 2);
+        for t in tris.iter() {
+            let (a, b, c) = t.into_points();
+            assert!([a, b, c].contains(&points[1]));
+            assert!([a, b, c].contains(&points[3]));
+
+            // empty circumcircles
+            assert!(points
+                .iter()
+                .all(|&p| !t.in_circumcircle(p) || [a, b, c].contains(&p)));
+        }
+    }
+
+    #[test]
+    fn triangulate_region() {
+        use crate::two::{dist::Dist, Arc};
+
+        let square = Boundary::from_points(&[
+            (0.0, 0.0).into(),
+            (4.0, 0.0).into(),
+            (4.0, 4.0).into(),
+            (0.0, 4.0).into(),
+        ]);
+        let hole = Boundary::new(vec![Arc::from_center_ang(
+            (2.0, 2.0).into(),
+            1.0,
+            0.0,
+            0.0,
+            true,
+        )]);
+        let region = Region::new(square, vec![hole]).unwrap();
+
+        for max_area in [None, Some(0.1)] {
+            let tris = region.triangulate(max_area);
+            assert!(tris.iter().all(|t| t.dir() == Orient::Positive));
+
+            // the chords of the hole cut off a little less than the circle
+            let total: f64 = tris.iter().map(tri_area).sum();
+            assert!((total - region.area()).abs() < 1e-3);
+
+            // nothing inside the hole, everything inside the square
+            for t in tris.iter() {
+                let (a, b, c) = t.into_points();
+                assert!(a.dist((2.0, 2.0).into()) > 1.0 - 1e-9);
+                assert!(!t.in_triangle((2.0, 2.0).into()));
+                assert!(!t.in_triangle((2.5, 2.3).into()));
+                assert!(region.contains((a + b + c) / 3.0));
+            }
+
+            if let Some(max_area) = max_area {
+                assert!(tris.iter().all(|t| tri_area(t) <= max_area));
+            }
+        }
+    }
+}
@@ -0,0 +1,223 @@
+// displacements and directions, as distinct from the positions held by Point
+// a vector has no location, so shifting leaves it alone, and adding it to a point moves the point
+// subtracting one point from another gives the vector between them
+
+use std::fmt;
+use std::ops::{Add, AddAssign, Div, Mul, Neg, Sub, SubAssign};
+
+use super::Point;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Vector {
+    pub x: f64,
+    pub y: f64,
+}
+
+impl Vector {
+    pub const ZERO: Vector = Vector { x: 0.0, y: 0.0 };
+
+    pub fn new(x: f64, y: f64) -> Self {
+        Vector { x, y }
+    }
+
+    pub fn unit(ang: f64) -> Self {
+        Vector {
+            x: ang.cos(),
+            y: ang.sin(),
+        }
+    }
+
+    pub fn between(p: Point, q: Point) -> Self {
+        // the displacement taking p to q, as q - p
+        Vector {
+            x: q.x - p.x,
+            y: q.y - p.y,
+        }
+    }
+
+    pub fn norm(self) -> f64 {
+        (self.x.powi(2) + self.y.powi(2)).sqrt()
+    }
+
+    pub fn normalize(self) -> Vector {
+        // unit vector in the same direction
+        self / self.norm()
+    }
+
+    pub fn dot(self, other: Vector) -> f64 {
+        (self.x * other.x) + (self.y * other.y)
+    }
+
+    pub fn cross(self, other: Vector) -> f64 {
+        // z component of the 3d cross product, positive if other is counterclockwise of self
+        (self.x * other.y) - (self.y * other.x)
+    }
+
+    pub fn angle_between(self, other: Vector) -> f64 {
+        // unsigned angle between the two, in [0, pi]
+        self.cross(other).abs().atan2(self.dot(other))
+    }
+
+    pub fn signed_angle(self, other: Vector) -> f64 {
+        // angle turning self onto other, in (-pi, pi], positive counterclockwise
+        let ang = self.cross(other).atan2(self.dot(other));
+        if ang == -std::f64::consts::PI {
+            std::f64::consts::PI
+        } else {
+            ang
+        }
+    }
+
+    pub fn perp(self) -> Vector {
+        // rotated a quarter turn counterclockwise
+        Vector {
+            x: -self.y,
+            y: self.x,
+        }
+    }
+
+    pub fn rotate(self, ang: f64) -> Vector {
+        // rotated counterclockwise by ang
+        let (sin, cos) = ang.sin_cos();
+        Vector {
+            x: self.x * cos - self.y * sin,
+            y: self.x * sin + self.y * cos,
+        }
+    }
+
+    pub fn ang(self) -> f64 {
+        // direction from the x axis, in [0, 2pi) as for Point::ang
+        let res = self.y.atan2(self.x);
+        if res < 0.0 {
+            res + std::f64::consts::TAU
+        } else {
+            res
+        }
+    }
+
+    pub fn eps_eq(self, other: Vector, epsilon: f64) -> bool {
+        // equal to within epsilon in each coordinate
+        (self.x - other.x).abs() <= epsilon && (self.y - other.y).abs() <= epsilon
+    }
+}
+
+impl fmt::Display for Vector {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "<{}, {}>", self.x, self.y)
+    }
+}
+
+impl From<(f64, f64)> for Vector {
+    fn from(v: (f64, f64)) -> Self {
+        Vector { x: v.0, y: v.1 }
+    }
+}
+
+impl Add for Vector {
+    type Output = Vector;
+
+    fn add(self, other: Vector) -> Vector {
+        Vector::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl Sub for Vector {
+    type Output = Vector;
+
+    fn sub(self, other: Vector) -> Vector {
+        Vector::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl Mul<f64> for Vector {
+    type Output = Vector;
+
+    fn mul(self, k: f64) -> Vector {
+        Vector::new(self.x * k, self.y * k)
+    }
+}
+
+impl Div<f64> for Vector {
+    type Output = Vector;
+
+    fn div(self, k: f64) -> Vector {
+        Vector::new(self.x / k, self.y / k)
+    }
+}
+
+impl Neg for Vector {
+    type Output = Vector;
+
+    fn neg(self) -> Vector {
+        Vector::new(-self.x, -self.y)
+    }
+}
+
+impl Add<Vector> for Point {
+    type Output = Point;
+
+    fn add(self, v: Vector) -> Point {
+        Point::new(self.x + v.x, self.y + v.y)
+    }
+}
+
+impl Sub<Vector> for Point {
+    type Output = Point;
+
+    fn sub(self, v: Vector) -> Point {
+        Point::new(self.x - v.x, self.y - v.y)
+    }
+}
+
+impl AddAssign<Vector> for Point {
+    fn add_assign(&mut self, v: Vector) {
+        *self = *self + v;
+    }
+}
+
+impl SubAssign<Vector> for Point {
+    fn sub_assign(&mut self, v: Vector) {
+        *self = *self - v;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::two::shift::Shift;
+
+    #[test]
+    fn vector_ops() {
+        let (p, q) = (Point::new(1.0, 2.0), Point::new(4.0, 6.0));
+        let v = q - p;
+        assert_eq!(v, Vector::new(3.0, 4.0));
+        assert_eq!(v, Vector::between(p, q));
+        assert_eq!(p + v, q);
+        assert_eq!(q - v, p);
+        assert_eq!(v.norm(), 5.0);
+        assert!(v.normalize().eps_eq(Vector::new(0.6, 0.8), 1e-12));
+
+        assert_eq!(v.perp(), Vector::new(-4.0, 3.0));
+        assert_eq!(v.dot(v.perp()), 0.0);
+        assert_eq!(v.cross(v.perp()), 25.0);
+        assert!(v
+            .rotate(std::f64::consts::FRAC_PI_2)
+            .eps_eq(v.perp(), 1e-12));
+        assert!((Vector::unit(1.0).ang() - 1.0).abs() < 1e-12);
+
+        let mut r = p;
+        r += v * 2.0;
+        r -= -v;
+        assert_eq!(r, Point::new(10.0, 14.0));
+    }
+
+    #[test]
+    fn shift_ignores_vectors() {
+        let (v, by) = (Vector::new(3.0, -1.0), Point::new(10.0, 20.0));
+        assert_eq!(v.shift(by), v);
+        assert_eq!(v.shift_subtract(by), v);
+
+        // as against a point with the same coordinates
+        assert_eq!(Point::new(3.0, -1.0).shift(by), Point::new(13.0, 19.0));
+    }
+}
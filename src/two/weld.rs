@@ -0,0 +1,95 @@
+// merging coincident vertices, e.g. when stitching meshes or boundaries together
+
+use std::collections::HashMap;
+
+use super::Point;
+
+// hands out one index per distinct vertex, where vertices within tol of each other (in each
+// coordinate) are the same vertex
+// the first point welded to an index is kept as its position
+#[derive(Clone, Debug)]
+pub struct VertexWelder {
+    tol: f64,
+    points: Vec<Point>,
+    cells: HashMap<(i64, i64), Vec<usize>>,
+}
+
+impl VertexWelder {
+    pub fn new(tol: f64) -> Self {
+        assert!(tol > 0.0);
+        VertexWelder {
+            tol,
+            points: Vec::new(),
+            cells: HashMap::new(),
+        }
+    }
+
+    pub fn weld(&mut self, p: Point) -> usize {
+        // index of the vertex at p, adding a new vertex if there is none
+        // a match is at most one grid cell away, so only the neighbouring cells are searched
+        let (i, j) = p.quantize(self.tol);
+        for di in -1..=1 {
+            for dj in -1..=1 {
+                let found = self.cells.get(&(i + di, j + dj)).and_then(|cell| {
+                    cell.iter()
+                        .copied()
+                        .find(|&k| self.points[k].eps_eq(p, self.tol))
+                });
+                if let Some(k) = found {
+                    return k;
+                }
+            }
+        }
+
+        let k = self.points.len();
+        self.points.push(p);
+        self.cells.entry((i, j)).or_default().push(k);
+        k
+    }
+
+    pub fn len(&self) -> usize {
+        self.points.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.points.is_empty()
+    }
+
+    pub fn points(&self) -> &[Point] {
+        &self.points
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn weld_near_duplicates() {
+        let tol = 1e-6;
+        let mut welder = VertexWelder::new(tol);
+
+        // the corners of a unit square, each repeated with some noise
+        // including noise that straddles a grid cell boundary
+        let raw = [
+            (0.0, 0.0),
+            (1.0, 0.0),
+            (1.0, 1.0),
+            (0.0, 1.0),
+            (4e-7, -3e-7),
+            (1.0 + 1e-9, 0.0),
+            (1.0, 1.0 - 6e-7),
+            (0.0, 1.0 + 4e-7),
+            (0.5 * tol - 1e-9, 0.0),
+            (0.5 * tol + 1e-9, 0.0),
+        ];
+        let indices: Vec<usize> = raw.iter().map(|&p| welder.weld(p.into())).collect();
+
+        assert_eq!(welder.len(), 4);
+        assert_eq!(indices, vec![0, 1, 2, 3, 0, 1, 2, 3, 0, 0]);
+        assert_eq!(welder.points()[2], Point::new(1.0, 1.0));
+
+        // points further apart than tol stay separate
+        assert_eq!(welder.weld((0.0, 3.0 * tol).into()), 4);
+    }
+}
@@ -0,0 +1,199 @@
+use std::fmt;
+use std::fmt::Write;
+
+use super::boundary::{Boundary, Edge};
+use super::{Point, Segment};
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum WktError {
+    // the text did not start with the expected geometry tag
+    WrongType,
+    // unbalanced or missing parentheses
+    Syntax,
+    // a coordinate could not be parsed as a pair of numbers
+    BadCoordinate(String),
+    // polygon rings must have at least three distinct points
+    TooFewPoints,
+    // polygon rings must end on their starting point
+    Unclosed,
+    // interior rings are not supported
+    Holes,
+}
+
+impl fmt::Display for WktError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WktError::WrongType => write!(f, "expected a POLYGON"),
+            WktError::Syntax => write!(f, "malformed parentheses"),
+            WktError::BadCoordinate(s) => write!(f, "bad coordinate '{}'", s),
+            WktError::TooFewPoints => {
+                write!(f, "polygon ring needs at least three distinct points")
+            }
+            WktError::Unclosed => write!(f, "polygon ring is not closed"),
+            WktError::Holes => write!(f, "polygons with holes are not supported"),
+        }
+    }
+}
+
+impl std::error::Error for WktError {}
+
+fn write_coord(s: &mut String, p: Point) {
+    write!(s, "{} {}", p.x, p.y).unwrap();
+}
+
+fn parse_coord(s: &str) -> Result<Point, WktError> {
+    let bad = || WktError::BadCoordinate(s.trim().to_string());
+
+    let mut parts = s.split_whitespace();
+    let x = parts.next().ok_or_else(bad)?.parse().map_err(|_| bad())?;
+    let y = parts.next().ok_or_else(bad)?.parse().map_err(|_| bad())?;
+    if parts.next().is_some() {
+        return Err(bad());
+    }
+
+    Ok(Point::new(x, y))
+}
+
+impl Point {
+    pub fn to_wkt(&self) -> String {
+        let mut s = String::from("POINT(");
+        write_coord(&mut s, *self);
+        s.push(')');
+        s
+    }
+}
+
+impl Segment {
+    pub fn to_wkt(&self) -> String {
+        let mut s = String::from("LINESTRING(");
+        write_coord(&mut s, self.p());
+        s.push_str(", ");
+        write_coord(&mut s, self.q());
+        s.push(')');
+        s
+    }
+}
+
+impl Boundary {
+    pub fn to_wkt(&self, len: f64) -> String {
+        // arcs are flattened into segments with target length len
+        // wkt rings repeat the first point at the end
+        let mut s = String::from("POLYGON((");
+        write_coord(&mut s, self.points()[0]);
+
+        for e in self.edges().flat_map(|e| e.into_segments(len)) {
+            s.push_str(", ");
+            write_coord(&mut s, e.q());
+        }
+
+        s.push_str("))");
+        s
+    }
+
+    pub fn from_wkt(text: &str) -> Result<Boundary, WktError> {
+        // parses a single-ring POLYGON into a boundary of segments
+        let text = text.trim();
+        let body = text
+            .get(..7)
+            .filter(|tag| tag.eq_ignore_ascii_case("POLYGON"))
+            .map(|_| text[7..].trim())
+            .ok_or(WktError::WrongType)?;
+
+        let rings = body
+            .strip_prefix('(')
+            .and_then(|b| b.strip_suffix(')'))
+            .ok_or(WktError::Syntax)?
+            .trim();
+        let ring = rings
+            .strip_prefix('(')
+            .and_then(|r| r.strip_suffix(')'))
+            .ok_or(WktError::Syntax)?;
+        if ring.contains('(') || ring.contains(')') {
+            return Err(WktError::Holes);
+        }
+
+        let points = ring
+            .split(',')
+            .map(parse_coord)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        if points.len() < 4 {
+            return Err(WktError::TooFewPoints);
+        }
+        if points.first() != points.last() {
+            return Err(WktError::Unclosed);
+        }
+        let mut distinct: Vec<Point> = Vec::new();
+        for p in points.iter() {
+            if !distinct.contains(p) {
+                distinct.push(*p);
+            }
+        }
+        if distinct.len() < 3 {
+            return Err(WktError::TooFewPoints);
+        }
+
+        let edges = points
+            .windows(2)
+            .map(|pq| Edge::Segment(Segment::new(pq[0], pq[1])));
+        Ok(Boundary::new(edges))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn triangle_round_trip() {
+        let edges = vec![
+            Segment::new((0.0, 0.0).into(), (2.0, 0.0).into()),
+            Segment::new((2.0, 0.0).into(), (1.0, 1.5).into()),
+            Segment::new((1.0, 1.5).into(), (0.0, 0.0).into()),
+        ];
+        let bound = Boundary::new(edges);
+
+        let text = bound.to_wkt(0.1);
+        assert_eq!(text, "POLYGON((0 0, 2 0, 1 1.5, 0 0))");
+
+        let parsed = Boundary::from_wkt(&text).unwrap();
+        assert_eq!(parsed.points(), bound.points());
+        assert!((parsed.area() - bound.area()).abs() < 1e-9);
+    }
+
+    #[test]
+    fn point_segment_wkt() {
+        assert_eq!(Point::new(1.0, -2.5).to_wkt(), "POINT(1 -2.5)");
+
+        let s = Segment::new((0.0, 0.0).into(), (3.0, 4.0).into());
+        assert_eq!(s.to_wkt(), "LINESTRING(0 0, 3 4)");
+    }
+
+    #[test]
+    fn bad_wkt() {
+        assert_eq!(
+            Boundary::from_wkt("LINESTRING(0 0, 1 1)").unwrap_err(),
+            WktError::WrongType
+        );
+        assert_eq!(
+            Boundary::from_wkt("POLYGON((0 0, 1 0, 1 1))").unwrap_err(),
+            WktError::TooFewPoints
+        );
+        assert_eq!(
+            Boundary::from_wkt("POLYGON((0 0, 0 0, 0 0, 0 0))").unwrap_err(),
+            WktError::TooFewPoints
+        );
+        assert_eq!(
+            Boundary::from_wkt("POLYGON((0 0, 1 0, 0 0, 1 0, 0 0))").unwrap_err(),
+            WktError::TooFewPoints
+        );
+        assert_eq!(
+            Boundary::from_wkt("POLYGON((0 0, 1 0, 1 1, 0 1))").unwrap_err(),
+            WktError::Unclosed
+        );
+        assert!(matches!(
+            Boundary::from_wkt("POLYGON((0 0, 1 x, 1 1, 0 0))"),
+            Err(WktError::BadCoordinate(_))
+        ));
+    }
+}